@@ -0,0 +1,257 @@
+// This module houses batch ("ensemble") integration of the same dynamics over many
+// initial conditions, plus the quasi-random (Sobol) sampling used to seed such ensembles.
+
+use crate::integrators::{explicit_rk_combine, explicit_rk_stages, ButcherTableau};
+use nalgebra::DVector;
+use numpy::{ndarray::Dim, PyArray, PyArrayMethods, PyReadonlyArray2, ToPyArray};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyTuple;
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+const SOBOL_MAX_DIM: usize = 6;
+const SOBOL_MAX_BITS: usize = 30;
+
+/// Degrees of the primitive polynomials used to build Sobol direction numbers, and their
+/// initial direction-number seeds, for the first six dimensions — the standard small
+/// table underlying the Bratley & Fox construction (as tabulated in, e.g., Press et al.,
+/// "Numerical Recipes", §7.7).
+const SOBOL_DEGREE: [u32; SOBOL_MAX_DIM] = [1, 2, 3, 3, 4, 4];
+const SOBOL_POLY: [u32; SOBOL_MAX_DIM] = [0, 1, 1, 2, 1, 4];
+const SOBOL_INIT: [[u64; 4]; SOBOL_MAX_DIM] = [
+    [1, 0, 0, 0],
+    [1, 3, 0, 0],
+    [1, 3, 1, 0],
+    [1, 1, 1, 0],
+    [1, 1, 3, 3],
+    [1, 3, 5, 13],
+];
+
+/// Generates the Sobol direction numbers `v[bit]` (pre-scaled so the final coordinate is
+/// `v[bit] / 2^SOBOL_MAX_BITS`) for one dimension, via the primitive-polynomial recurrence.
+fn sobol_direction_numbers(dim: usize) -> Vec<u64> {
+    let degree = SOBOL_DEGREE[dim] as usize;
+    let poly = SOBOL_POLY[dim];
+    let mut v = vec![0u64; SOBOL_MAX_BITS + 1];
+    for bit in 1..=degree {
+        v[bit] = SOBOL_INIT[dim][bit - 1] << (SOBOL_MAX_BITS - bit);
+    }
+    for bit in (degree + 1)..=SOBOL_MAX_BITS {
+        let mut term = v[bit - degree] ^ (v[bit - degree] >> degree);
+        for k in 1..degree {
+            if (poly >> (degree - 1 - k)) & 1 == 1 {
+                term ^= v[bit - k];
+            }
+        }
+        v[bit] = term;
+    }
+    v
+}
+
+/// Generates the first `count` points of a `dim`-dimensional Sobol low-discrepancy
+/// sequence in `[0, 1)^dim`, stepping through Gray-code order so each new point only XORs
+/// one cached direction number per dimension. This is the quasi-Monte-Carlo sampler used
+/// throughout the SciML ecosystem's `QuasiMonteCarlo.jl` for seeding ensembles of initial
+/// conditions, which explores a state-space box far more uniformly than i.i.d. sampling.
+fn sobol_sequence(dim: usize, count: usize) -> PyResult<Vec<Vec<f64>>> {
+    if dim == 0 || dim > SOBOL_MAX_DIM {
+        return Err(PyValueError::new_err(format!(
+            "Sobol sampling only supports 1..={} dimensions, got {}.",
+            SOBOL_MAX_DIM, dim
+        )));
+    }
+    let directions: Vec<Vec<u64>> = (0..dim).map(sobol_direction_numbers).collect();
+    let scale = (1u64 << SOBOL_MAX_BITS) as f64;
+
+    let mut x = vec![0u64; dim];
+    let mut points = Vec::with_capacity(count);
+    for i in 0..count {
+        if i > 0 {
+            // Gray(i) and Gray(i-1) differ at exactly the bit position given by the
+            // number of trailing zeros of i.
+            let bit = i.trailing_zeros() as usize + 1;
+            for (xi, dirs) in x.iter_mut().zip(directions.iter()) {
+                *xi ^= dirs[bit];
+            }
+        }
+        points.push(x.iter().map(|&xi| xi as f64 / scale).collect());
+    }
+    Ok(points)
+}
+
+/// Parameters for an `Ensemble.solve` run: a `(n_trajectories, state_dim)` array of
+/// initial conditions, all integrated against the same `dynamics` callback.
+#[pyclass]
+#[derive(Clone)]
+pub struct EnsembleParams {
+    #[pyo3(get, set)]
+    dynamics: PyObject,
+    #[pyo3(get, set)]
+    initial_states: PyObject,
+    #[pyo3(get, set)]
+    t_start: f64,
+    #[pyo3(get, set)]
+    t_end: f64,
+    #[pyo3(get, set)]
+    h: f64,
+}
+
+#[pymethods]
+impl EnsembleParams {
+    #[new]
+    #[pyo3(signature = (dynamics, initial_states, t_start, t_end, h))]
+    fn new(
+        dynamics: PyObject,
+        initial_states: PyObject,
+        t_start: f64,
+        t_end: f64,
+        h: f64,
+    ) -> Self {
+        Self {
+            dynamics,
+            initial_states,
+            t_start,
+            t_end,
+            h,
+        }
+    }
+}
+
+/// # Ensemble Batch Solver
+///
+/// Integrates the same dynamics from a whole batch of initial conditions in one call,
+/// returning a stacked `(n_trajectories, n_points, state_dim)` trajectory array. Reuses
+/// the same Butcher-tableau stage/combine helpers as `ExplicitRK`; the batch is driven by
+/// classical RK4 (the default, or `Ensemble.rk4()`) or forward Euler (`Ensemble.euler()`).
+#[pyclass]
+#[derive(Clone)]
+pub struct Ensemble {
+    tableau: ButcherTableau,
+}
+
+impl Ensemble {
+    pub fn new() -> Self {
+        Ensemble {
+            tableau: ButcherTableau::rk4(),
+        }
+    }
+}
+
+#[pymethods]
+impl Ensemble {
+    #[new]
+    fn __new__() -> Self {
+        Ensemble::new()
+    }
+
+    #[staticmethod]
+    fn rk4() -> Self {
+        Ensemble {
+            tableau: ButcherTableau::rk4(),
+        }
+    }
+
+    #[staticmethod]
+    fn euler() -> Self {
+        Ensemble {
+            tableau: ButcherTableau::euler(),
+        }
+    }
+
+    /// Generates `count` Sobol-sampled initial conditions inside the box `[lower, upper]`
+    /// (one entry per state component), suitable for seeding `Ensemble.solve`.
+    #[staticmethod]
+    #[pyo3(signature = (lower, upper, count))]
+    fn sobol_initial_conditions(lower: Vec<f64>, upper: Vec<f64>, count: usize) -> PyResult<Vec<Vec<f64>>> {
+        if lower.len() != upper.len() {
+            return Err(PyValueError::new_err(
+                "'lower' and 'upper' must have the same length.",
+            ));
+        }
+        let dim = lower.len();
+        let unit_points = sobol_sequence(dim, count)?;
+        Ok(unit_points
+            .into_iter()
+            .map(|point| {
+                point
+                    .iter()
+                    .enumerate()
+                    .map(|(i, &u)| lower[i] + u * (upper[i] - lower[i]))
+                    .collect()
+            })
+            .collect())
+    }
+
+    /// Integrates `dynamics` forward from every row of `initial_states` (shape
+    /// `(n_trajectories, state_dim)`) over `[t_start, t_end]` with fixed step `h`.
+    ///
+    /// Because the Python `dynamics` callback needs the GIL, each trajectory only
+    /// reacquires it for the duration of a single RHS call (the same short-lived
+    /// `Python::with_gil` pattern `lyapunov::parallel_finite_diff_jacobian` uses), so the
+    /// outer loop over trajectories can still run across threads with `rayon` while the
+    /// GIL is released for the batch via `py.allow_threads`.
+    fn solve(&self, py: Python, mode: PyObject) -> PyResult<PyObject> {
+        let params = mode.extract::<EnsembleParams>(py)?;
+        let dynamics = params.dynamics;
+        let initial_states = params.initial_states.extract::<PyReadonlyArray2<f64>>(py)?;
+        let t_start = params.t_start;
+        let t_end = params.t_end;
+        let h = params.h;
+
+        let array_view = initial_states.as_array();
+        let n_trajectories = array_view.nrows();
+        let state_dim = array_view.ncols();
+        let num_steps = ((t_end - t_start) / h).ceil() as usize;
+        let tableau = self.tableau.clone();
+
+        let initial_vectors: Vec<DVector<f64>> = array_view
+            .outer_iter()
+            .map(|row| DVector::from_iterator(state_dim, row.iter().cloned()))
+            .collect();
+
+        let results: Vec<PyResult<Vec<DVector<f64>>>> = py.allow_threads(|| {
+            initial_vectors
+                .into_par_iter()
+                .map(|y0| {
+                    let mut current_y = y0;
+                    let mut current_t = t_start;
+                    let mut trajectory = Vec::with_capacity(num_steps + 1);
+                    trajectory.push(current_y.clone());
+
+                    for _ in 0..num_steps {
+                        let mut call_dynamics =
+                            |t_eval: f64, y_eval: &DVector<f64>| -> PyResult<DVector<f64>> {
+                                Python::with_gil(|py| {
+                                    let y_py = y_eval.as_slice().to_pyarray_bound(py);
+                                    let args =
+                                        PyTuple::new_bound(py, &[t_eval.into_py(py), y_py.into_py(py)]);
+                                    let result = dynamics.call_bound(py, args, None)?;
+                                    let bound_result = result.bind(py);
+                                    let py_array: &PyArray<f64, Dim<[usize; 1]>> =
+                                        bound_result.extract()?;
+                                    let readonly_array = py_array.readonly();
+                                    Ok(DVector::from_column_slice(readonly_array.as_slice()?))
+                                })
+                            };
+                        let k = explicit_rk_stages(&tableau, current_t, &current_y, h, &mut call_dynamics)?;
+                        let (y_next, _) = explicit_rk_combine(&tableau, &current_y, h, &k);
+                        current_y = y_next;
+                        current_t += h;
+                        trajectory.push(current_y.clone());
+                    }
+                    Ok(trajectory)
+                })
+                .collect()
+        });
+
+        let mut flat: Vec<f64> = Vec::with_capacity(n_trajectories * (num_steps + 1) * state_dim);
+        for result in results {
+            for state in result? {
+                flat.extend(state.iter().cloned());
+            }
+        }
+        let stacked = PyArray::from_vec_bound(py, flat)
+            .reshape((n_trajectories, num_steps + 1, state_dim))?;
+        Ok(stacked.to_object(py))
+    }
+}
@@ -23,6 +23,55 @@ pub trait Stepper<'py, A: Approach<'py>> {
     fn step<F>(&self, t: f64, y: &DVector<f64>, h: f64, f: &mut F) -> PyResult<A::Ret>
     where
         F: FnMut(f64, &DVector<f64>) -> PyResult<DVector<f64>>;
+
+    /// The classical order of the underlying method. Used by the `Adaptive` approach's
+    /// PI step-size controller to pick its gains; defaults to a conservative 4.
+    fn order(&self) -> usize {
+        4
+    }
+}
+
+/// A per-component (or scalar, broadcast) absolute/relative tolerance, as accepted by
+/// `AdaptiveParams`: either a single float applied to every state component, or a list
+/// with one entry per component.
+#[derive(Clone)]
+pub enum Tolerance {
+    Scalar(f64),
+    PerComponent(Vec<f64>),
+}
+
+impl Tolerance {
+    fn component(&self, i: usize) -> f64 {
+        match self {
+            Tolerance::Scalar(value) => *value,
+            Tolerance::PerComponent(values) => values[i % values.len()],
+        }
+    }
+}
+
+impl<'source> FromPyObject<'source> for Tolerance {
+    fn extract(ob: &'source PyAny) -> PyResult<Self> {
+        if let Ok(scalar) = ob.extract::<f64>() {
+            Ok(Tolerance::Scalar(scalar))
+        } else {
+            let values = ob.extract::<Vec<f64>>()?;
+            if values.is_empty() {
+                return Err(PyValueError::new_err(
+                    "per-component tolerance must have at least one entry",
+                ));
+            }
+            Ok(Tolerance::PerComponent(values))
+        }
+    }
+}
+
+impl IntoPy<PyObject> for Tolerance {
+    fn into_py(self, py: Python) -> PyObject {
+        match self {
+            Tolerance::Scalar(value) => value.into_py(py),
+            Tolerance::PerComponent(values) => values.into_py(py),
+        }
+    }
 }
 
 // --- 2. Approach Structs (Internal Logic) ---
@@ -33,6 +82,7 @@ pub struct Explicit<'py> {
     pub t_start: f64,
     pub t_end: f64,
     pub h: f64,
+    pub t_eval: Option<Vec<f64>>,
 }
 
 pub struct Adaptive<'py> {
@@ -41,8 +91,11 @@ pub struct Adaptive<'py> {
     pub t_start: f64,
     pub t_end: f64,
     pub initial_h: f64,
-    pub abstol: f64,
-    pub reltol: f64,
+    pub abstol: Tolerance,
+    pub reltol: Tolerance,
+    pub max_step: Option<f64>,
+    pub min_step: Option<f64>,
+    pub t_eval: Option<Vec<f64>>,
 }
 
 pub struct Implicit<'py> {
@@ -51,6 +104,92 @@ pub struct Implicit<'py> {
     pub t_start: f64,
     pub t_end: f64,
     pub h: f64,
+    pub t_eval: Option<Vec<f64>>,
+}
+
+/// Cubic Hermite interpolation over an accepted step `[t_n, t_n + h]`, using the states
+/// and derivatives at both endpoints. This is the cheap, method-agnostic dense-output
+/// formula used to sample the solution at arbitrary requested times.
+fn hermite_interpolate(
+    t: f64,
+    t_n: f64,
+    h: f64,
+    y_n: &DVector<f64>,
+    f_n: &DVector<f64>,
+    y_next: &DVector<f64>,
+    f_next: &DVector<f64>,
+) -> DVector<f64> {
+    let theta = (t - t_n) / h;
+    let theta2 = theta * theta;
+    let theta3 = theta2 * theta;
+    let h00 = 2.0 * theta3 - 3.0 * theta2 + 1.0;
+    let h10 = theta3 - 2.0 * theta2 + theta;
+    let h01 = -2.0 * theta3 + 3.0 * theta2;
+    let h11 = theta3 - theta2;
+    h00 * y_n + (h * h10) * f_n + h01 * y_next + (h * h11) * f_next
+}
+
+/// Builds the dense-output trajectory at the requested `t_eval` times from the states
+/// accepted at the internal step times, re-evaluating the RHS at each bracketing node to
+/// get the slopes the Hermite interpolant needs.
+fn build_dense_output<F>(
+    times: &[f64],
+    trajectory: &[DVector<f64>],
+    t_eval: &[f64],
+    f: &mut F,
+) -> PyResult<Vec<DVector<f64>>>
+where
+    F: FnMut(f64, &DVector<f64>) -> PyResult<DVector<f64>>,
+{
+    // A zero-duration integration (`t_start == t_end`) accepts only the initial state, so
+    // there's no bracketing pair to interpolate between — every requested time just gets
+    // that single state back.
+    if times.len() < 2 {
+        let only_state = trajectory.first().cloned().unwrap_or_else(|| DVector::zeros(0));
+        return Ok(t_eval.iter().map(|_| only_state.clone()).collect());
+    }
+
+    // Times run in ascending order for forward integration and descending order for
+    // backward integration (`t_end < t_start`); the bracket search below has to advance
+    // in whichever direction `times` is actually ordered.
+    let forward = times[1] >= times[0];
+    let mut output = Vec::with_capacity(t_eval.len());
+    for &t in t_eval {
+        let mut idx = 0;
+        while idx + 2 < times.len() && (if forward { times[idx + 1] < t } else { times[idx + 1] > t }) {
+            idx += 1;
+        }
+        let t_n = times[idx];
+        let t_next = times[idx + 1];
+        let y_n = &trajectory[idx];
+        let y_next = &trajectory[idx + 1];
+        let h = t_next - t_n;
+        let f_n = f(t_n, y_n)?;
+        let f_next = f(t_next, y_next)?;
+        output.push(hermite_interpolate(t, t_n, h, y_n, &f_n, y_next, &f_next));
+    }
+    Ok(output)
+}
+
+/// Packs a dense-output trajectory and its requested sample times into a
+/// `(trajectory, times)` tuple. (Without `t_eval`, `Adaptive` instead returns
+/// `(trajectory, times, rejected_steps)`.)
+fn dense_output_result(
+    py: Python,
+    dense: Vec<DVector<f64>>,
+    t_eval: &[f64],
+) -> PyResult<PyObject> {
+    let num_points = dense.len();
+    let state_dim = dense.first().map_or(0, |v| v.len());
+    let flat: Vec<f64> = dense
+        .into_iter()
+        .flat_map(|v| v.iter().cloned().collect::<Vec<f64>>())
+        .collect();
+    let result_array = PyArray::from_vec_bound(py, flat).reshape((num_points, state_dim))?;
+    let times_array = PyArray::from_vec_bound(py, t_eval.to_vec());
+    let result_tuple =
+        PyTuple::new_bound(py, &[result_array.to_object(py), times_array.to_object(py)]);
+    Ok(result_tuple.to_object(py))
 }
 
 // --- 3. Integration Loops (Internal Logic) ---
@@ -65,8 +204,15 @@ impl<'py> Approach<'py> for Explicit<'py> {
         let mut current_t = self.t_start;
         let mut current_y = initial_y;
 
-        let num_steps = ((self.t_end - self.t_start) / self.h).ceil() as usize;
+        // Per the standard ODE-problem convention, `t_end < t_start` means integrate
+        // backward; give `h` the matching sign regardless of what the caller passed.
+        let direction = if self.t_end < self.t_start { -1.0 } else { 1.0 };
+        let h = self.h.abs() * direction;
+
+        let num_steps = ((self.t_end - self.t_start) / h).ceil() as usize;
+        let mut times: Vec<f64> = Vec::with_capacity(num_steps + 1);
         let mut trajectory: Vec<DVector<f64>> = Vec::with_capacity(num_steps + 1);
+        times.push(current_t);
         trajectory.push(current_y.clone());
 
         let mut call_dynamics = |t_eval: f64, y_eval: &DVector<f64>| -> PyResult<DVector<f64>> {
@@ -80,28 +226,44 @@ impl<'py> Approach<'py> for Explicit<'py> {
         };
 
         for _ in 0..num_steps {
-            let y_next = stepper.step(current_t, &current_y, self.h, &mut call_dynamics)?;
+            let y_next = stepper.step(current_t, &current_y, h, &mut call_dynamics)?;
             current_y = y_next;
-            current_t += self.h;
+            current_t += h;
+            times.push(current_t);
             trajectory.push(current_y.clone());
         }
 
-        let num_points = trajectory.len();
-        let state_dim = if num_points > 0 {
-            trajectory[0].len()
-        } else {
-            0
-        };
-        let flat_trajectory: Vec<f64> = trajectory
-            .into_iter()
-            .flat_map(|v| v.iter().cloned().collect::<Vec<f64>>())
-            .collect();
-        let result_array =
-            PyArray::from_vec_bound(py, flat_trajectory).reshape((num_points, state_dim))?;
-        Ok(result_array.to_object(py))
+        if let Some(t_eval) = &self.t_eval {
+            let dense = build_dense_output(&times, &trajectory, t_eval, &mut call_dynamics)?;
+            return dense_output_result(py, dense, t_eval);
+        }
+
+        fixed_step_result(py, trajectory, times)
     }
 }
 
+/// Packs a fixed-step trajectory and its accepted step times into the same
+/// `(trajectory, times)` tuple shape the `t_eval` dense-output path returns, so `Explicit`/`Implicit` have one consistent return shape regardless of whether
+/// `t_eval` was supplied.
+fn fixed_step_result(py: Python, trajectory: Vec<DVector<f64>>, times: Vec<f64>) -> PyResult<PyObject> {
+    let num_points = trajectory.len();
+    let state_dim = if num_points > 0 {
+        trajectory[0].len()
+    } else {
+        0
+    };
+    let flat_trajectory: Vec<f64> = trajectory
+        .into_iter()
+        .flat_map(|v| v.iter().cloned().collect::<Vec<f64>>())
+        .collect();
+    let result_array =
+        PyArray::from_vec_bound(py, flat_trajectory).reshape((num_points, state_dim))?;
+    let times_array = PyArray::from_vec_bound(py, times);
+    let result_tuple =
+        PyTuple::new_bound(py, &[result_array.to_object(py), times_array.to_object(py)]);
+    Ok(result_tuple.to_object(py))
+}
+
 impl<'py> Approach<'py> for Adaptive<'py> {
     type Ret = (DVector<f64>, DVector<f64>);
     fn integration_loop<S>(self, py: Python, stepper: S) -> PyResult<PyObject>
@@ -110,7 +272,10 @@ impl<'py> Approach<'py> for Adaptive<'py> {
         {
         let mut current_y = DVector::from_column_slice(self.initial_state.as_slice()?);
         let mut current_t = self.t_start;
-        let mut current_h = self.initial_h;
+        // Per the standard ODE-problem convention, `t_end < t_start` means integrate
+        // backward; give `h` the matching sign regardless of what the caller passed.
+        let direction = if self.t_end < self.t_start { -1.0 } else { 1.0 };
+        let mut current_h = self.initial_h.abs() * direction;
 
         let mut times: Vec<f64> = Vec::new();
         let mut trajectory: Vec<DVector<f64>> = Vec::new();
@@ -131,41 +296,66 @@ impl<'py> Approach<'py> for Adaptive<'py> {
         const MIN_FACTOR: f64 = 0.2;
         const MAX_FACTOR: f64 = 10.0;
 
-        while current_t < self.t_end {
-            if current_t + current_h > self.t_end {
+        // PI controller gains, scaled by the method's order per Söderlind's formula.
+        let q = (stepper.order() + 1) as f64;
+        let k_i_gain = 0.7 / q;
+        let k_p_gain = 0.4 / q;
+        let max_step = self.max_step.unwrap_or(f64::INFINITY);
+        let min_step = self.min_step.unwrap_or(0.0);
+        let mut err_prev: f64 = 1.0;
+        let mut rejected_steps: usize = 0;
+
+        while (self.t_end - current_t) * direction > 0.0 {
+            if (current_t + current_h - self.t_end) * direction > 0.0 {
                 current_h = self.t_end - current_t;
             }
-            if current_h <= 0.0 {
+            if current_h.abs() <= 0.0 {
                 break;
             }
 
             let (y_next, error_vec) =
                 stepper.step(current_t, &current_y, current_h, &mut call_dynamics)?;
-            let error_norm = error_vec.norm();
-            let y_norm = current_y.norm().max(y_next.norm());
-            let tolerance = self.abstol + self.reltol * y_norm;
-            let error = if tolerance > 0.0 {
-                error_norm / tolerance
-            } else {
-                0.0
-            };
 
-            if error <= 1.0 {
+            // Weighted RMS error norm with per-component absolute/relative tolerances.
+            let dim = current_y.len();
+            let mut squared_sum = 0.0;
+            for i in 0..dim {
+                let scale = self.abstol.component(i)
+                    + self.reltol.component(i) * current_y[i].abs().max(y_next[i].abs());
+                let ratio = if scale > 0.0 { error_vec[i] / scale } else { 0.0 };
+                squared_sum += ratio * ratio;
+            }
+            let err = (squared_sum / dim as f64).sqrt();
+
+            if err <= 1.0 {
                 current_t += current_h;
                 current_y = y_next;
                 times.push(current_t);
                 trajectory.push(current_y.clone());
-            }
 
-            let factor = if error > 0.0 {
-                let factor = SAFETY * (1.0 / error).powf(0.2);
-                factor.clamp(MIN_FACTOR, MAX_FACTOR)
+                let factor = if err > 0.0 {
+                    (SAFETY * err.powf(-k_i_gain) * err_prev.powf(k_p_gain))
+                        .clamp(MIN_FACTOR, MAX_FACTOR)
+                } else {
+                    MAX_FACTOR
+                };
+                let magnitude = (current_h.abs() * factor).clamp(min_step, max_step);
+                current_h = magnitude * direction;
+                err_prev = err.max(1e-10);
             } else {
-                MAX_FACTOR
-            };
-            current_h *= factor;
+                rejected_steps += 1;
+                let factor = (SAFETY * err.powf(-k_i_gain)).clamp(MIN_FACTOR, MAX_FACTOR);
+                let magnitude = (current_h.abs() * factor).clamp(min_step, max_step);
+                current_h = magnitude * direction;
+                err_prev = 1.0;
+            }
+        }
+
+        if let Some(t_eval) = &self.t_eval {
+            let dense = build_dense_output(&times, &trajectory, t_eval, &mut call_dynamics)?;
+            return dense_output_result(py, dense, t_eval);
         }
-        
+
         let num_points = trajectory.len();
         let state_dim = if num_points > 0 {
             trajectory[0].len()
@@ -179,11 +369,16 @@ impl<'py> Approach<'py> for Adaptive<'py> {
         let traj_array =
         PyArray::from_vec_bound(py, flat_trajectory).reshape((num_points, state_dim))?;
         let time_array = PyArray::from_vec_bound(py, times);
-        let result_tuple =
-        PyTuple::new_bound(py, &[traj_array.to_object(py), time_array.to_object(py)]);
+        let result_tuple = PyTuple::new_bound(
+            py,
+            &[
+                traj_array.to_object(py),
+                time_array.to_object(py),
+                rejected_steps.to_object(py),
+            ],
+        );
 
-        let a = Ok(result_tuple.to_object(py));
-        a
+        Ok(result_tuple.to_object(py))
     }
 }
 
@@ -197,8 +392,15 @@ impl<'py> Approach<'py> for Implicit<'py> {
         let mut current_t = self.t_start;
         let mut current_y = initial_y;
 
-        let num_steps = ((self.t_end - self.t_start) / self.h).ceil() as usize;
+        // Per the standard ODE-problem convention, `t_end < t_start` means integrate
+        // backward; give `h` the matching sign regardless of what the caller passed.
+        let direction = if self.t_end < self.t_start { -1.0 } else { 1.0 };
+        let h = self.h.abs() * direction;
+
+        let num_steps = ((self.t_end - self.t_start) / h).ceil() as usize;
+        let mut times: Vec<f64> = Vec::with_capacity(num_steps + 1);
         let mut trajectory: Vec<DVector<f64>> = Vec::with_capacity(num_steps + 1);
+        times.push(current_t);
         trajectory.push(current_y.clone());
 
         let mut call_dynamics = |t_eval: f64, y_eval: &DVector<f64>| -> PyResult<DVector<f64>> {
@@ -212,25 +414,19 @@ impl<'py> Approach<'py> for Implicit<'py> {
         };
 
         for _ in 0..num_steps {
-            let y_next = stepper.step(current_t, &current_y, self.h, &mut call_dynamics)?;
+            let y_next = stepper.step(current_t, &current_y, h, &mut call_dynamics)?;
             current_y = y_next;
-            current_t += self.h;
+            current_t += h;
+            times.push(current_t);
             trajectory.push(current_y.clone());
         }
 
-        let num_points = trajectory.len();
-        let state_dim = if num_points > 0 {
-            trajectory[0].len()
-        } else {
-            0
-        };
-        let flat_trajectory: Vec<f64> = trajectory
-            .into_iter()
-            .flat_map(|v| v.iter().cloned().collect::<Vec<f64>>())
-            .collect();
-        let result_array =
-            PyArray::from_vec_bound(py, flat_trajectory).reshape((num_points, state_dim))?;
-        Ok(result_array.to_object(py))
+        if let Some(t_eval) = &self.t_eval {
+            let dense = build_dense_output(&times, &trajectory, t_eval, &mut call_dynamics)?;
+            return dense_output_result(py, dense, t_eval);
+        }
+
+        fixed_step_result(py, trajectory, times)
     }
 }
 
@@ -240,6 +436,10 @@ impl<'py> Approach<'py> for Implicit<'py> {
 pub struct Rk45;
 
 impl<'py> Stepper<'py, Adaptive<'py>> for Rk45 {
+    fn order(&self) -> usize {
+        5
+    }
+
     fn step<F>(
         &self,
         t: f64,
@@ -391,10 +591,477 @@ impl<'py> Stepper<'py, Implicit<'py>> for Euler {
         F: FnMut(f64, &DVector<f64>) -> PyResult<DVector<f64>>,
     {
         let t_next = t + h;
-        // FIX: Calculate initial_guess BEFORE defining the closure `g` to avoid borrow checker conflict.
-        let initial_guess = y + h * f(t, y)?;
-        // FIX: The call to newton_raphson_solve is now correct.
-        newton_raphson_solve(y, initial_guess, t_next, h, f)
+        let f_n = f(t, y)?;
+        // Implicit Euler is the theta-method with theta = 1 (fully implicit RHS).
+        theta_method_solve(y, &f_n, t_next, h, 1.0, f)
+    }
+}
+
+#[pyclass]
+#[derive(Copy, Clone)]
+pub struct Trapezoidal;
+
+#[pymethods]
+impl Trapezoidal {
+    #[new]
+    fn new() -> Self {
+        Trapezoidal
+    }
+
+    fn solve<'py>(&self, py: Python<'py>, mode: PyObject) -> PyResult<PyObject> {
+        if let Ok(params) = mode.extract::<ImplicitParams>(py) {
+            let initial_state = params.initial_state.extract::<PyReadonlyArray1<f64>>(py)?;
+            Implicit {
+                dynamics: params.dynamics,
+                initial_state,
+                t_start: params.t_start,
+                t_end: params.t_end,
+                h: params.h,
+                t_eval: params.t_eval.clone(),
+            }
+            .integration_loop(py, *self)
+        } else {
+            Err(PyTypeError::new_err(
+                "Trapezoidal solver requires an 'Implicit' mode.",
+            ))
+        }
+    }
+}
+
+impl<'py> Stepper<'py, Implicit<'py>> for Trapezoidal {
+    fn step<F>(
+        &self,
+        t: f64,
+        y: &DVector<f64>,
+        h: f64,
+        f: &mut F,
+    ) -> PyResult<<Implicit<'py> as Approach<'py>>::Ret>
+    where
+        F: FnMut(f64, &DVector<f64>) -> PyResult<DVector<f64>>,
+    {
+        let t_next = t + h;
+        let f_n = f(t, y)?;
+        // Trapezoidal (a.k.a. Crank-Nicolson) is the theta-method with theta = 1/2.
+        theta_method_solve(y, &f_n, t_next, h, 0.5, f)
+    }
+}
+
+impl<'py> Stepper<'py, Explicit<'py>> for Trapezoidal {
+    fn step<F>(
+        &self,
+        _t: f64,
+        _y: &DVector<f64>,
+        _h: f64,
+        _f: &mut F,
+    ) -> PyResult<<Explicit<'py> as Approach<'py>>::Ret>
+    where
+        F: FnMut(f64, &DVector<f64>) -> PyResult<DVector<f64>>,
+    {
+        Err(PyNotImplementedError::new_err(
+            "Trapezoidal is an implicit-only method; use the 'Implicit' mode.",
+        ))
+    }
+}
+
+// --- 4b. Generic Butcher-Tableau Stepper ---
+// Rather than hand-coding a new `Stepper` for every explicit RK method, `ExplicitRK`
+// carries the tableau as data so new methods (DOPRI5, Bogacki-Shampine, Tsit5, Fehlberg,
+// classic RK4, ...) can be selected from Python without writing new Rust code.
+
+/// Coefficients for an explicit Runge-Kutta method in Butcher-tableau form: nodes `c`,
+/// strictly lower-triangular stage coefficients `a`, solution weights `b`, and an
+/// optional embedded lower-order weights `b_hat` for adaptive error estimation.
+#[derive(Clone)]
+pub struct ButcherTableau {
+    pub c: Vec<f64>,
+    pub a: Vec<Vec<f64>>,
+    pub b: Vec<f64>,
+    pub b_hat: Option<Vec<f64>>,
+    pub order: usize,
+}
+
+impl ButcherTableau {
+    fn stages(&self) -> usize {
+        self.c.len()
+    }
+
+    pub fn euler() -> Self {
+        ButcherTableau {
+            c: vec![0.0],
+            a: vec![vec![]],
+            b: vec![1.0],
+            b_hat: None,
+            order: 1,
+        }
+    }
+
+    pub fn rk4() -> Self {
+        ButcherTableau {
+            c: vec![0.0, 0.5, 0.5, 1.0],
+            a: vec![vec![], vec![0.5], vec![0.0, 0.5], vec![0.0, 0.0, 1.0]],
+            b: vec![1.0 / 6.0, 1.0 / 3.0, 1.0 / 3.0, 1.0 / 6.0],
+            b_hat: None,
+            order: 4,
+        }
+    }
+
+    /// Bogacki-Shampine RK23, the default "cheap adaptive" pair used by e.g. MATLAB's `ode23`.
+    pub fn bogacki_shampine() -> Self {
+        ButcherTableau {
+            c: vec![0.0, 0.5, 0.75, 1.0],
+            a: vec![
+                vec![],
+                vec![0.5],
+                vec![0.0, 0.75],
+                vec![2.0 / 9.0, 1.0 / 3.0, 4.0 / 9.0],
+            ],
+            b: vec![2.0 / 9.0, 1.0 / 3.0, 4.0 / 9.0, 0.0],
+            b_hat: Some(vec![7.0 / 24.0, 1.0 / 4.0, 1.0 / 3.0, 1.0 / 8.0]),
+            order: 3,
+        }
+    }
+
+    /// Dormand-Prince 5(4), the same coefficients hard-coded in the `Rk45` stepper.
+    pub fn dopri5() -> Self {
+        ButcherTableau {
+            c: vec![
+                0.0,
+                1.0 / 5.0,
+                3.0 / 10.0,
+                4.0 / 5.0,
+                8.0 / 9.0,
+                1.0,
+                1.0,
+            ],
+            a: vec![
+                vec![],
+                vec![1.0 / 5.0],
+                vec![3.0 / 40.0, 9.0 / 40.0],
+                vec![44.0 / 45.0, -56.0 / 15.0, 32.0 / 9.0],
+                vec![
+                    19372.0 / 6561.0,
+                    -25360.0 / 2187.0,
+                    64448.0 / 6561.0,
+                    -212.0 / 729.0,
+                ],
+                vec![
+                    9017.0 / 3168.0,
+                    -355.0 / 33.0,
+                    46732.0 / 5247.0,
+                    49.0 / 176.0,
+                    -5103.0 / 18656.0,
+                ],
+                vec![
+                    35.0 / 384.0,
+                    0.0,
+                    500.0 / 1113.0,
+                    125.0 / 192.0,
+                    -2187.0 / 6784.0,
+                    11.0 / 84.0,
+                ],
+            ],
+            b: vec![
+                35.0 / 384.0,
+                0.0,
+                500.0 / 1113.0,
+                125.0 / 192.0,
+                -2187.0 / 6784.0,
+                11.0 / 84.0,
+                0.0,
+            ],
+            b_hat: Some(vec![
+                5179.0 / 57600.0,
+                0.0,
+                7571.0 / 16695.0,
+                393.0 / 640.0,
+                -92097.0 / 339200.0,
+                187.0 / 2100.0,
+                1.0 / 40.0,
+            ]),
+            order: 5,
+        }
+    }
+
+    /// Tsitouras 5(4), a modern low-error-constant alternative to DOPRI5.
+    pub fn tsit5() -> Self {
+        ButcherTableau {
+            c: vec![0.0, 0.161, 0.327, 0.9, 0.9800255409045097, 1.0, 1.0],
+            a: vec![
+                vec![],
+                vec![0.161],
+                vec![-0.008480655492356989, 0.335480655492357],
+                vec![2.8971530571054935, -6.359448489975075, 4.3622954328695815],
+                vec![
+                    5.325864828439257,
+                    -11.748883564062828,
+                    7.4955393428898365,
+                    -0.09249506636175525,
+                ],
+                vec![
+                    5.86145544294642,
+                    -12.92096931784711,
+                    8.159367898576159,
+                    -0.071584973281401,
+                    -0.028269050394068383,
+                ],
+                vec![
+                    0.09646076681806523,
+                    0.01,
+                    0.4798896504144996,
+                    1.379008574103742,
+                    -3.290069515436081,
+                    2.324710524099774,
+                ],
+            ],
+            b: vec![
+                0.09646076681806523,
+                0.01,
+                0.4798896504144996,
+                1.379008574103742,
+                -3.290069515436081,
+                2.324710524099774,
+                0.0,
+            ],
+            b_hat: Some(vec![
+                0.09824077787029101,
+                0.010816434459656746,
+                0.4720087724042376,
+                1.5237195812770048,
+                -3.872426680888636,
+                2.7827926300289607,
+                -1.0 / 66.0,
+            ]),
+            order: 5,
+        }
+    }
+
+    /// Runge-Kutta-Fehlberg 4(5).
+    pub fn fehlberg45() -> Self {
+        ButcherTableau {
+            c: vec![0.0, 1.0 / 4.0, 3.0 / 8.0, 12.0 / 13.0, 1.0, 0.5],
+            a: vec![
+                vec![],
+                vec![1.0 / 4.0],
+                vec![3.0 / 32.0, 9.0 / 32.0],
+                vec![1932.0 / 2197.0, -7200.0 / 2197.0, 7296.0 / 2197.0],
+                vec![439.0 / 216.0, -8.0, 3680.0 / 513.0, -845.0 / 4104.0],
+                vec![
+                    -8.0 / 27.0,
+                    2.0,
+                    -3544.0 / 2565.0,
+                    1859.0 / 4104.0,
+                    -11.0 / 40.0,
+                ],
+            ],
+            b: vec![
+                16.0 / 135.0,
+                0.0,
+                6656.0 / 12825.0,
+                28561.0 / 56430.0,
+                -9.0 / 50.0,
+                2.0 / 55.0,
+            ],
+            b_hat: Some(vec![
+                25.0 / 216.0,
+                0.0,
+                1408.0 / 2565.0,
+                2197.0 / 4104.0,
+                -1.0 / 5.0,
+                0.0,
+            ]),
+            order: 5,
+        }
+    }
+}
+
+/// Computes all stage derivatives `k_i = f(t + c_i*h, y + h*Σ_{j<i} a_ij*k_j)` for a tableau.
+pub(crate) fn explicit_rk_stages<F>(
+    tableau: &ButcherTableau,
+    t: f64,
+    y: &DVector<f64>,
+    h: f64,
+    f: &mut F,
+) -> PyResult<Vec<DVector<f64>>>
+where
+    F: FnMut(f64, &DVector<f64>) -> PyResult<DVector<f64>>,
+{
+    let mut k: Vec<DVector<f64>> = Vec::with_capacity(tableau.stages());
+    for i in 0..tableau.stages() {
+        let mut y_stage = y.clone();
+        for (j, a_ij) in tableau.a[i].iter().enumerate() {
+            if *a_ij != 0.0 {
+                y_stage += h * *a_ij * &k[j];
+            }
+        }
+        let t_stage = t + tableau.c[i] * h;
+        k.push(f(t_stage, &y_stage)?);
+    }
+    Ok(k)
+}
+
+/// Combines stage derivatives into `y_next = y + h*Σ b_i*k_i`, and, when the tableau
+/// carries embedded weights, the error estimate `h*Σ (b_i - b_hat_i)*k_i`.
+pub(crate) fn explicit_rk_combine(
+    tableau: &ButcherTableau,
+    y: &DVector<f64>,
+    h: f64,
+    k: &[DVector<f64>],
+) -> (DVector<f64>, Option<DVector<f64>>) {
+    let mut y_next = y.clone();
+    for (b_i, k_i) in tableau.b.iter().zip(k.iter()) {
+        y_next += h * *b_i * k_i;
+    }
+
+    let error = tableau.b_hat.as_ref().map(|b_hat| {
+        let mut err = DVector::<f64>::zeros(y.len());
+        for ((b_i, b_hat_i), k_i) in tableau.b.iter().zip(b_hat.iter()).zip(k.iter()) {
+            err += h * (*b_i - *b_hat_i) * k_i;
+        }
+        err
+    });
+
+    (y_next, error)
+}
+
+#[pyclass]
+#[derive(Clone)]
+pub struct ExplicitRK {
+    tableau: ButcherTableau,
+}
+
+#[pymethods]
+impl ExplicitRK {
+    #[new]
+    #[pyo3(signature = (c, a, b, b_hat=None, order=1))]
+    fn new(c: Vec<f64>, a: Vec<Vec<f64>>, b: Vec<f64>, b_hat: Option<Vec<f64>>, order: usize) -> Self {
+        ExplicitRK {
+            tableau: ButcherTableau {
+                c,
+                a,
+                b,
+                b_hat,
+                order,
+            },
+        }
+    }
+
+    #[staticmethod]
+    fn euler() -> Self {
+        ExplicitRK {
+            tableau: ButcherTableau::euler(),
+        }
+    }
+
+    #[staticmethod]
+    fn rk4() -> Self {
+        ExplicitRK {
+            tableau: ButcherTableau::rk4(),
+        }
+    }
+
+    #[staticmethod]
+    fn bogacki_shampine() -> Self {
+        ExplicitRK {
+            tableau: ButcherTableau::bogacki_shampine(),
+        }
+    }
+
+    #[staticmethod]
+    fn dopri5() -> Self {
+        ExplicitRK {
+            tableau: ButcherTableau::dopri5(),
+        }
+    }
+
+    #[staticmethod]
+    fn tsit5() -> Self {
+        ExplicitRK {
+            tableau: ButcherTableau::tsit5(),
+        }
+    }
+
+    #[staticmethod]
+    fn fehlberg45() -> Self {
+        ExplicitRK {
+            tableau: ButcherTableau::fehlberg45(),
+        }
+    }
+
+    fn solve<'py>(&self, py: Python<'py>, mode: PyObject) -> PyResult<PyObject> {
+        if let Ok(params) = mode.extract::<AdaptiveParams>(py) {
+            let initial_state = params.initial_state.extract::<PyReadonlyArray1<f64>>(py)?;
+            Adaptive {
+                dynamics: params.dynamics,
+                initial_state,
+                t_start: params.t_start,
+                t_end: params.t_end,
+                initial_h: params.h,
+                abstol: params.abstol,
+                reltol: params.reltol,
+                max_step: params.max_step,
+                min_step: params.min_step,
+                t_eval: params.t_eval.clone(),
+            }
+            .integration_loop(py, self.clone())
+        } else if let Ok(params) = mode.extract::<ExplicitParams>(py) {
+            let initial_state = params.initial_state.extract::<PyReadonlyArray1<f64>>(py)?;
+            Explicit {
+                dynamics: params.dynamics,
+                initial_state,
+                t_start: params.t_start,
+                t_end: params.t_end,
+                h: params.h,
+                t_eval: params.t_eval.clone(),
+            }
+            .integration_loop(py, self.clone())
+        } else {
+            Err(PyTypeError::new_err(
+                "ExplicitRK solver requires an 'Explicit' or 'Adaptive' mode.",
+            ))
+        }
+    }
+}
+
+impl<'py> Stepper<'py, Explicit<'py>> for ExplicitRK {
+    fn step<F>(
+        &self,
+        t: f64,
+        y: &DVector<f64>,
+        h: f64,
+        f: &mut F,
+    ) -> PyResult<<Explicit<'py> as Approach<'py>>::Ret>
+    where
+        F: FnMut(f64, &DVector<f64>) -> PyResult<DVector<f64>>,
+    {
+        let k = explicit_rk_stages(&self.tableau, t, y, h, f)?;
+        let (y_next, _error) = explicit_rk_combine(&self.tableau, y, h, &k);
+        Ok(y_next)
+    }
+}
+
+impl<'py> Stepper<'py, Adaptive<'py>> for ExplicitRK {
+    fn order(&self) -> usize {
+        self.tableau.order
+    }
+
+    fn step<F>(
+        &self,
+        t: f64,
+        y: &DVector<f64>,
+        h: f64,
+        f: &mut F,
+    ) -> PyResult<<Adaptive<'py> as Approach<'py>>::Ret>
+    where
+        F: FnMut(f64, &DVector<f64>) -> PyResult<DVector<f64>>,
+    {
+        if self.tableau.b_hat.is_none() {
+            return Err(PyValueError::new_err(
+                "This Butcher tableau has no embedded error estimator; it cannot be used in 'Adaptive' mode.",
+            ));
+        }
+        let k = explicit_rk_stages(&self.tableau, t, y, h, f)?;
+        let (y_next, error) = explicit_rk_combine(&self.tableau, y, h, &k);
+        Ok((y_next, error.unwrap()))
     }
 }
 
@@ -420,51 +1087,361 @@ where
     Ok(jacobian)
 }
 
-// FIX: Refactored the function signature and body to resolve the borrow-checker error.
-// It no longer takes `g` as an argument. Instead, it takes the previous state `y`
-// and constructs the closure `g` internally. This prevents `f` from being borrowed
-// mutably by the closure and the function call simultaneously.
-fn newton_raphson_solve<F>(
-    y: &DVector<f64>,
-    initial_guess: DVector<f64>,
+/// Solves `A x = b` by classic (Jacobi) iterative refinement, splitting `A = D + (L+U)`
+/// and iterating `x_{k+1} = D^{-1} * (b - (L+U) * x_k)` until convergence.
+///
+/// Falls back to a direct `nalgebra` LU solve when the diagonal is near-singular, since
+/// Jacobi iteration is not guaranteed to converge in that case.
+fn jacobi_solve(matrix: &DMatrix<f64>, b: &DVector<f64>, tol: f64, max_iter: usize) -> DVector<f64> {
+    let dim = b.len();
+    let diag_eps = 1e-12;
+    let has_near_singular_diagonal = (0..dim).any(|i| matrix[(i, i)].abs() < diag_eps);
+
+    if has_near_singular_diagonal {
+        return matrix
+            .clone()
+            .lu()
+            .solve(b)
+            .unwrap_or_else(|| DVector::zeros(dim));
+    }
+
+    let mut x = DVector::<f64>::zeros(dim);
+    for _ in 0..max_iter {
+        let mut x_next = DVector::<f64>::zeros(dim);
+        for i in 0..dim {
+            let mut off_diagonal_sum = 0.0;
+            for j in 0..dim {
+                if j != i {
+                    off_diagonal_sum += matrix[(i, j)] * x[j];
+                }
+            }
+            x_next[i] = (b[i] - off_diagonal_sum) / matrix[(i, i)];
+        }
+        let delta_norm = (&x_next - &x).norm();
+        x = x_next;
+        if delta_norm < tol {
+            return x;
+        }
+    }
+    x
+}
+
+/// Solves the implicit theta-method step `y_next = y_n + h * (theta * f(t_next, y_next)
+/// + (1 - theta) * f_n)` for `y_next` by Newton iteration, reusing the same
+/// finite-difference Jacobian machinery for every theta (1 = implicit Euler, 1/2 =
+/// trapezoidal). The inner linear solve at each Newton iteration uses `jacobi_solve`.
+fn theta_method_solve<F>(
+    y_n: &DVector<f64>,
+    f_n: &DVector<f64>,
     t_next: f64,
     h: f64,
+    theta: f64,
     f: &mut F,
 ) -> PyResult<DVector<f64>>
 where
     F: FnMut(f64, &DVector<f64>) -> PyResult<DVector<f64>>,
 {
-    let mut x = initial_guess;
-    let dim = x.len();
+    let dim = y_n.len();
     let identity = DMatrix::<f64>::identity(dim, dim);
-    let max_iter = 20;
-    let tolerance = 1e-8;
+    let max_newton_iter = 20;
+    let newton_tolerance = 1e-8;
     let jacobian_eps = 1e-6;
+    let jacobi_tolerance = 1e-10;
+    let max_jacobi_iter = 200;
 
-    for _ in 0..max_iter {
-        // Define the function g(y_next) = y_next - y_prev - h * f(t_next, y_next)
-        // whose root we want to find.
-        let g_eval = {
-            let f_eval = f(t_next, &x)?;
-            &x - y - h * f_eval
-        };
+    let mut x = y_n + h * f_n;
 
-        if g_eval.norm() < tolerance {
+    for _ in 0..max_newton_iter {
+        let f_next = f(t_next, &x)?;
+        let g_eval = &x - y_n - h * (theta * &f_next + (1.0 - theta) * f_n);
+
+        if g_eval.norm() < newton_tolerance {
             return Ok(x);
         }
 
         let jacobian_f = approximate_jacobian(t_next, &x, f, jacobian_eps)?;
-        let jacobian_g = &identity - h * jacobian_f;
+        let jacobian_g = &identity - (theta * h) * jacobian_f;
+
+        let delta = jacobi_solve(&jacobian_g, &(-&g_eval), jacobi_tolerance, max_jacobi_iter);
+        x += delta;
+    }
+    Err(PyValueError::new_err("Newton's method did not converge."))
+}
+
+// --- 4c. Stiff Steppers (Rosenbrock, SDIRK) ---
+// Both reuse `approximate_jacobian` to build a single iteration matrix per step and
+// factorize it with `nalgebra`'s `lu()` once, then reuse that factorization for every
+// stage's linear solve instead of re-deriving it per stage.
+
+/// Coefficients for the classical 2-stage, 2nd-order L-stable Rosenbrock-Wanner method
+/// ("Ros2"; Hairer & Wanner, "Solving ODEs II", §IV.7): the shared diagonal `gamma` used to
+/// form `W = I - h*gamma*J`, and the method's order.
+#[derive(Clone, Copy)]
+pub struct RosenbrockTableau {
+    pub gamma: f64,
+    pub order: usize,
+}
+
+impl RosenbrockTableau {
+    pub fn ros2() -> Self {
+        RosenbrockTableau {
+            gamma: 1.0 + 1.0 / std::f64::consts::SQRT_2,
+            order: 2,
+        }
+    }
+}
+
+/// Advances one Rosenbrock step. `W = I - h*gamma*J` is formed and LU-factorized once,
+/// then reused to solve for both stage vectors `k1`, `k2`:
+/// `W*k1 = h*f(y)`, `W*k2 = h*f(y + k1) - 2*k1`, `y_next = y + 3/2*k1 + 1/2*k2`.
+/// The embedded first-order estimate `y_hat = y + k1` gives the error estimate
+/// `y_next - y_hat` for the `Adaptive` approach's step-size controller.
+fn rosenbrock_step<F>(
+    tableau: &RosenbrockTableau,
+    t: f64,
+    y: &DVector<f64>,
+    h: f64,
+    f: &mut F,
+) -> PyResult<(DVector<f64>, DVector<f64>)>
+where
+    F: FnMut(f64, &DVector<f64>) -> PyResult<DVector<f64>>,
+{
+    let dim = y.len();
+    let identity = DMatrix::<f64>::identity(dim, dim);
+    let jacobian = approximate_jacobian(t, y, f, 1e-6)?;
+    let w = &identity - (h * tableau.gamma) * &jacobian;
+    let lu = w.lu();
+
+    let f0 = f(t, y)?;
+    let k1 = lu
+        .solve(&(h * &f0))
+        .ok_or_else(|| PyValueError::new_err("Rosenbrock iteration matrix is singular."))?;
+
+    let f1 = f(t + h, &(y + &k1))?;
+    let k2 = lu
+        .solve(&(h * &f1 - 2.0 * &k1))
+        .ok_or_else(|| PyValueError::new_err("Rosenbrock iteration matrix is singular."))?;
+
+    let y_next = y + 1.5 * &k1 + 0.5 * &k2;
+    let y_hat = y + &k1;
+    let error = &y_next - &y_hat;
+    Ok((y_next, error))
+}
 
-        if let Some(inv_jacobian_g) = jacobian_g.try_inverse() {
-            x -= inv_jacobian_g * g_eval; // Corrected update step
+#[pyclass]
+#[derive(Clone, Copy)]
+pub struct Rosenbrock {
+    tableau: RosenbrockTableau,
+}
+
+#[pymethods]
+impl Rosenbrock {
+    #[staticmethod]
+    fn ros2() -> Self {
+        Rosenbrock {
+            tableau: RosenbrockTableau::ros2(),
+        }
+    }
+
+    fn solve<'py>(&self, py: Python<'py>, mode: PyObject) -> PyResult<PyObject> {
+        if let Ok(params) = mode.extract::<AdaptiveParams>(py) {
+            let initial_state = params.initial_state.extract::<PyReadonlyArray1<f64>>(py)?;
+            Adaptive {
+                dynamics: params.dynamics,
+                initial_state,
+                t_start: params.t_start,
+                t_end: params.t_end,
+                initial_h: params.h,
+                abstol: params.abstol,
+                reltol: params.reltol,
+                max_step: params.max_step,
+                min_step: params.min_step,
+                t_eval: params.t_eval.clone(),
+            }
+            .integration_loop(py, *self)
         } else {
-            return Err(PyValueError::new_err(
-                "Failed to solve linear system in Newton's method (matrix is singular).",
-            ));
+            Err(PyTypeError::new_err(
+                "Rosenbrock solver requires an 'Adaptive' mode.",
+            ))
         }
     }
-    Err(PyValueError::new_err("Newton's method did not converge."))
+}
+
+impl<'py> Stepper<'py, Adaptive<'py>> for Rosenbrock {
+    fn order(&self) -> usize {
+        self.tableau.order
+    }
+
+    fn step<F>(
+        &self,
+        t: f64,
+        y: &DVector<f64>,
+        h: f64,
+        f: &mut F,
+    ) -> PyResult<<Adaptive<'py> as Approach<'py>>::Ret>
+    where
+        F: FnMut(f64, &DVector<f64>) -> PyResult<DVector<f64>>,
+    {
+        rosenbrock_step(&self.tableau, t, y, h, f)
+    }
+}
+
+/// Coefficients for a singly diagonally implicit RK (SDIRK) pair: every stage
+/// shares the same diagonal `gamma`, so its iteration matrix `W = I - h*gamma*J` needs to
+/// be formed and LU-factorized only once per step, rather than once per stage. `a` holds
+/// the strictly-lower-triangular explicit-part coefficients, `b` the solution weights, and
+/// `b_hat` an optional embedded lower-order weights for adaptive error estimation.
+/// Every stage, including the first, is solved implicitly with `gamma`; an explicit
+/// first stage (ESDIRK) cannot be expressed.
+#[derive(Clone)]
+pub struct SdirkTableau {
+    pub gamma: f64,
+    pub a: Vec<Vec<f64>>,
+    pub b: Vec<f64>,
+    pub b_hat: Option<Vec<f64>>,
+    pub order: usize,
+}
+
+impl SdirkTableau {
+    fn stages(&self) -> usize {
+        self.a.len()
+    }
+
+    /// Alexander's 2-stage, 2nd-order L-stable SDIRK, with an embedded 1st-order
+    /// (implicit-Euler-consistent) estimate for adaptive step-size control.
+    pub fn sdirk21() -> Self {
+        let gamma = 1.0 - 1.0 / std::f64::consts::SQRT_2;
+        SdirkTableau {
+            gamma,
+            a: vec![vec![], vec![1.0 - gamma]],
+            b: vec![1.0 - gamma, gamma],
+            b_hat: Some(vec![1.0, 0.0]),
+            order: 2,
+        }
+    }
+}
+
+/// Advances one SDIRK step. Forms and LU-factorizes `W = I - h*gamma*J` once, then
+/// reuses that factorization for the (modified) Newton solve at every stage.
+fn sdirk_step<F>(
+    tableau: &SdirkTableau,
+    t: f64,
+    y: &DVector<f64>,
+    h: f64,
+    f: &mut F,
+) -> PyResult<(DVector<f64>, Option<DVector<f64>>)>
+where
+    F: FnMut(f64, &DVector<f64>) -> PyResult<DVector<f64>>,
+{
+    let dim = y.len();
+    let identity = DMatrix::<f64>::identity(dim, dim);
+    let jacobian = approximate_jacobian(t, y, f, 1e-6)?;
+    let w = &identity - (h * tableau.gamma) * &jacobian;
+    let lu = w.lu();
+
+    let max_newton_iter = 20;
+    let newton_tolerance = 1e-8;
+
+    let mut stages: Vec<DVector<f64>> = Vec::with_capacity(tableau.stages());
+    for i in 0..tableau.stages() {
+        let mut y_stage = y.clone();
+        for (j, a_ij) in tableau.a[i].iter().enumerate() {
+            if *a_ij != 0.0 {
+                y_stage += h * *a_ij * &stages[j];
+            }
+        }
+        let t_stage = t + h * (tableau.a[i].iter().sum::<f64>() + tableau.gamma);
+
+        let mut k_i = f(t_stage, &y_stage)?;
+        for _ in 0..max_newton_iter {
+            let y_trial = &y_stage + h * tableau.gamma * &k_i;
+            let f_trial = f(t_stage, &y_trial)?;
+            let residual = &k_i - &f_trial;
+            if residual.norm() < newton_tolerance {
+                break;
+            }
+            let delta = lu.solve(&(-&residual)).ok_or_else(|| {
+                PyValueError::new_err("SDIRK stage's iteration matrix is singular.")
+            })?;
+            k_i += delta;
+        }
+        stages.push(k_i);
+    }
+
+    let mut y_next = y.clone();
+    for (b_i, k_i) in tableau.b.iter().zip(stages.iter()) {
+        y_next += h * *b_i * k_i;
+    }
+
+    let error = tableau.b_hat.as_ref().map(|b_hat| {
+        let mut err = DVector::<f64>::zeros(dim);
+        for ((b_i, b_hat_i), k_i) in tableau.b.iter().zip(b_hat.iter()).zip(stages.iter()) {
+            err += h * (*b_i - *b_hat_i) * k_i;
+        }
+        err
+    });
+
+    Ok((y_next, error))
+}
+
+#[pyclass]
+#[derive(Clone)]
+pub struct Sdirk {
+    tableau: SdirkTableau,
+}
+
+#[pymethods]
+impl Sdirk {
+    #[staticmethod]
+    fn sdirk21() -> Self {
+        Sdirk {
+            tableau: SdirkTableau::sdirk21(),
+        }
+    }
+
+    fn solve<'py>(&self, py: Python<'py>, mode: PyObject) -> PyResult<PyObject> {
+        if let Ok(params) = mode.extract::<AdaptiveParams>(py) {
+            let initial_state = params.initial_state.extract::<PyReadonlyArray1<f64>>(py)?;
+            Adaptive {
+                dynamics: params.dynamics,
+                initial_state,
+                t_start: params.t_start,
+                t_end: params.t_end,
+                initial_h: params.h,
+                abstol: params.abstol,
+                reltol: params.reltol,
+                max_step: params.max_step,
+                min_step: params.min_step,
+                t_eval: params.t_eval.clone(),
+            }
+            .integration_loop(py, self.clone())
+        } else {
+            Err(PyTypeError::new_err(
+                "SDIRK solver requires an 'Adaptive' mode.",
+            ))
+        }
+    }
+}
+
+impl<'py> Stepper<'py, Adaptive<'py>> for Sdirk {
+    fn order(&self) -> usize {
+        self.tableau.order
+    }
+
+    fn step<F>(
+        &self,
+        t: f64,
+        y: &DVector<f64>,
+        h: f64,
+        f: &mut F,
+    ) -> PyResult<<Adaptive<'py> as Approach<'py>>::Ret>
+    where
+        F: FnMut(f64, &DVector<f64>) -> PyResult<DVector<f64>>,
+    {
+        let (y_next, error) = sdirk_step(&self.tableau, t, y, h, f)?;
+        let error = error.unwrap_or_else(|| DVector::zeros(y.len()));
+        Ok((y_next, error))
+    }
 }
 
 // --- 5. PyO3 Class Definitions for Python API ---
@@ -482,18 +1459,29 @@ pub struct ExplicitParams {
     t_end: f64,
     #[pyo3(get, set)]
     h: f64,
+    #[pyo3(get, set)]
+    t_eval: Option<Vec<f64>>,
 }
 
 #[pymethods]
 impl ExplicitParams {
     #[new]
-    fn new(dynamics: PyObject, initial_state: PyObject, t_start: f64, t_end: f64, h: f64) -> Self {
+    #[pyo3(signature = (dynamics, initial_state, t_start, t_end, h, t_eval=None))]
+    fn new(
+        dynamics: PyObject,
+        initial_state: PyObject,
+        t_start: f64,
+        t_end: f64,
+        h: f64,
+        t_eval: Option<Vec<f64>>,
+    ) -> Self {
         Self {
             dynamics,
             initial_state,
             t_start,
             t_end,
             h,
+            t_eval,
         }
     }
 }
@@ -511,18 +1499,29 @@ pub struct ImplicitParams {
     t_end: f64,
     #[pyo3(get, set)]
     h: f64,
+    #[pyo3(get, set)]
+    t_eval: Option<Vec<f64>>,
 }
 
 #[pymethods]
 impl ImplicitParams {
     #[new]
-    fn new(dynamics: PyObject, initial_state: PyObject, t_start: f64, t_end: f64, h: f64) -> Self {
+    #[pyo3(signature = (dynamics, initial_state, t_start, t_end, h, t_eval=None))]
+    fn new(
+        dynamics: PyObject,
+        initial_state: PyObject,
+        t_start: f64,
+        t_end: f64,
+        h: f64,
+        t_eval: Option<Vec<f64>>,
+    ) -> Self {
         Self {
             dynamics,
             initial_state,
             t_start,
             t_end,
             h,
+            t_eval,
         }
     }
 }
@@ -541,23 +1540,36 @@ pub struct AdaptiveParams {
     #[pyo3(get, set)]
     h: f64,
     #[pyo3(get, set)]
-    abstol: f64,
+    abstol: Tolerance,
+    #[pyo3(get, set)]
+    reltol: Tolerance,
+    #[pyo3(get, set)]
+    max_step: Option<f64>,
+    #[pyo3(get, set)]
+    min_step: Option<f64>,
     #[pyo3(get, set)]
-    reltol: f64,
+    t_eval: Option<Vec<f64>>,
 }
 
 #[pymethods]
 impl AdaptiveParams {
     #[new]
-    #[pyo3(signature = (dynamics, initial_state, t_start, t_end, h, abstol=1e-6, reltol=1e-3))]
+    #[pyo3(signature = (
+        dynamics, initial_state, t_start, t_end, h,
+        abstol = Tolerance::Scalar(1e-6), reltol = Tolerance::Scalar(1e-3),
+        max_step = None, min_step = None, t_eval = None,
+    ))]
     fn new(
         dynamics: PyObject,
         initial_state: PyObject,
         t_start: f64,
         t_end: f64,
         h: f64,
-        abstol: f64,
-        reltol: f64,
+        abstol: Tolerance,
+        reltol: Tolerance,
+        max_step: Option<f64>,
+        min_step: Option<f64>,
+        t_eval: Option<Vec<f64>>,
     ) -> Self {
         Self {
             dynamics,
@@ -567,6 +1579,9 @@ impl AdaptiveParams {
             h,
             abstol,
             reltol,
+            max_step,
+            min_step,
+            t_eval,
         }
     }
 }
@@ -589,6 +1604,9 @@ impl Rk45 {
                 initial_h: params.h,
                 abstol: params.abstol,
                 reltol: params.reltol,
+                max_step: params.max_step,
+                min_step: params.min_step,
+                t_eval: params.t_eval.clone(),
             }
             .integration_loop(py, *self)
         } else {
@@ -615,6 +1633,7 @@ impl Rk4 {
                 t_start: params.t_start,
                 t_end: params.t_end,
                 h: params.h,
+                t_eval: params.t_eval.clone(),
             }
             .integration_loop(py, *self)
         } else if let Ok(params) = mode.extract::<ImplicitParams>(py) {
@@ -625,6 +1644,7 @@ impl Rk4 {
                 t_start: params.t_start,
                 t_end: params.t_end,
                 h: params.h,
+                t_eval: params.t_eval.clone(),
             }
             .integration_loop(py, *self)
         } else {
@@ -651,6 +1671,7 @@ impl Euler {
                 t_start: params.t_start,
                 t_end: params.t_end,
                 h: params.h,
+                t_eval: params.t_eval.clone(),
             }
             .integration_loop(py, *self)
         } else if let Ok(params) = mode.extract::<ImplicitParams>(py) {
@@ -661,6 +1682,7 @@ impl Euler {
                 t_start: params.t_start,
                 t_end: params.t_end,
                 h: params.h,
+                t_eval: params.t_eval.clone(),
             }
             .integration_loop(py, *self)
         } else {
@@ -3,14 +3,19 @@
 // with the Python interpreter.
 
 // Declare the modules corresponding to the other files in `src/`.
+mod ensemble;
 mod entropy;
 mod integrators;
 mod lyapunov;
 mod stats;
 
 // Use statements to bring the public classes from each module into scope.
+use ensemble::{Ensemble, EnsembleParams};
 use entropy::Entropy;
-use integrators::{AdaptiveParams, Euler, ExplicitParams, ImplicitParams, Rk4, Rk45};
+use integrators::{
+    AdaptiveParams, Euler, ExplicitParams, ExplicitRK, ImplicitParams, Rk4, Rk45, Rosenbrock,
+    Sdirk, Trapezoidal,
+};
 use lyapunov::Lyapunov;
 use stats::Stats;
 
@@ -28,11 +33,17 @@ fn _core(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<Rk45>()?;
     m.add_class::<Rk4>()?;
     m.add_class::<Euler>()?;
+    m.add_class::<Trapezoidal>()?;
+    m.add_class::<ExplicitRK>()?;
+    m.add_class::<Rosenbrock>()?;
+    m.add_class::<Sdirk>()?;
+    m.add_class::<Ensemble>()?;
 
     // --- Register Parameter Data Classes ---
     m.add_class::<ExplicitParams>()?;
     m.add_class::<ImplicitParams>()?;
     m.add_class::<AdaptiveParams>()?;
+    m.add_class::<EnsembleParams>()?;
 
     // --- Register Analysis Tool Classes ---
     m.add_class::<Lyapunov>()?;
@@ -1,9 +1,9 @@
-use crate::integrators::{Adaptive, Approach, Rk45}; // Import the Approach trait
+use crate::integrators::{Adaptive, Approach, Rk45, Stepper, Tolerance};
 use nalgebra::{DMatrix, DVector};
 use numpy::{ndarray::Dim, PyArray, PyArrayMethods, PyReadonlyArray1, ToPyArray};
 use pyo3::prelude::*;
 use pyo3::types::PyTuple;
-use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
 
 /// # Lyapunov Spectrum Calculator
 ///
@@ -19,6 +19,181 @@ impl Lyapunov {
     }
 }
 
+/// Evaluates the Python `dynamics(t, y)` callback for a single state vector.
+fn eval_dynamics(dynamics: &PyObject, py: Python, t: f64, y: &DVector<f64>) -> PyResult<DVector<f64>> {
+    let y_py = y.as_slice().to_pyarray_bound(py);
+    let args = PyTuple::new_bound(py, &[t.into_py(py), y_py.into_py(py)]);
+    let result = dynamics.call_bound(py, args, None)?;
+    let bound_result = result.bind(py);
+    let py_array: &PyArray<f64, Dim<[usize; 1]>> = bound_result.extract()?;
+    let readonly_array = py_array.readonly();
+    Ok(DVector::from_column_slice(readonly_array.as_slice()?))
+}
+
+/// Finite-difference Jacobian of the Python `dynamics` callback, with each column
+/// computed independently. Because every column only needs its own short-lived GIL
+/// acquisition (via `Python::with_gil`) rather than sharing the caller's `Python<'py>`
+/// token across threads, this is safe to parallelize with `rayon` — unlike re-entering
+/// full nested integrations concurrently, which is what used to deadlock here.
+fn parallel_finite_diff_jacobian(
+    dynamics: &PyObject,
+    t: f64,
+    y: &DVector<f64>,
+    eps: f64,
+) -> PyResult<DMatrix<f64>> {
+    let dim = y.len();
+    let columns: Vec<PyResult<DVector<f64>>> = (0..dim)
+        .into_par_iter()
+        .map(|j| {
+            Python::with_gil(|py| {
+                let mut y_plus = y.clone();
+                let mut y_minus = y.clone();
+                y_plus[j] += eps;
+                y_minus[j] -= eps;
+                let f_plus = eval_dynamics(dynamics, py, t, &y_plus)?;
+                let f_minus = eval_dynamics(dynamics, py, t, &y_minus)?;
+                Ok((f_plus - f_minus) / (2.0 * eps))
+            })
+        })
+        .collect();
+
+    let mut jacobian = DMatrix::<f64>::zeros(dim, dim);
+    for (j, column) in columns.into_iter().enumerate() {
+        jacobian.set_column(j, &column?);
+    }
+    Ok(jacobian)
+}
+
+/// Packs the base state `y` and deviation matrix `W` into a single flat vector so the
+/// augmented (variational) system can be advanced by the existing `Stepper` machinery.
+fn pack_augmented_state(y: &DVector<f64>, w: &DMatrix<f64>) -> DVector<f64> {
+    let dim = y.len();
+    let mut packed = DVector::<f64>::zeros(dim + dim * dim);
+    packed.rows_mut(0, dim).copy_from(y);
+    for j in 0..dim {
+        packed.rows_mut(dim + j * dim, dim).copy_from(&w.column(j));
+    }
+    packed
+}
+
+/// Inverse of [`pack_augmented_state`].
+fn unpack_augmented_state(packed: &DVector<f64>, dim: usize) -> (DVector<f64>, DMatrix<f64>) {
+    let y = DVector::from_column_slice(packed.rows(0, dim).as_slice());
+    let mut w = DMatrix::<f64>::zeros(dim, dim);
+    for j in 0..dim {
+        w.set_column(j, &packed.rows(dim + j * dim, dim));
+    }
+    (y, w)
+}
+
+/// Evaluates the variational-equation right-hand side `(ẏ, Ẇ) = (f(y), Df(y)·W)` on the
+/// packed augmented state, using the column-parallel central-difference Jacobian
+/// [`parallel_finite_diff_jacobian`] (the same scheme as the `approximate_jacobian` that
+/// feeds the implicit solvers in `integrators`).
+fn augmented_rhs(
+    dynamics: &PyObject,
+    py: Python,
+    eps: f64,
+    dim: usize,
+    t: f64,
+    packed: &DVector<f64>,
+) -> PyResult<DVector<f64>> {
+    let (y, w) = unpack_augmented_state(packed, dim);
+    let dy = eval_dynamics(dynamics, py, t, &y)?;
+    // `parallel_finite_diff_jacobian` spawns rayon workers that each reacquire the GIL via
+    // `Python::with_gil`. If the calling thread kept holding the GIL (via `py`) while it
+    // blocks on `.collect()`, those workers would deadlock waiting for a GIL this thread is
+    // simultaneously sitting on — so release it for the duration of the parallel section.
+    let jacobian = py.allow_threads(|| parallel_finite_diff_jacobian(dynamics, t, &y, eps))?;
+    let dw = jacobian * &w;
+    Ok(pack_augmented_state(&dy, &dw))
+}
+
+/// Integrates the augmented (state + deviation matrix) system forward by `duration`
+/// using the existing RK45 `Stepper` directly (bypassing the Python-callback-oriented
+/// `Approach`/`integration_loop` path, since the variational RHS lives entirely in Rust).
+fn integrate_variational_window(
+    dynamics: &PyObject,
+    py: Python,
+    eps: f64,
+    dim: usize,
+    y0: &DVector<f64>,
+    w0: &DMatrix<f64>,
+    t_start: f64,
+    duration: f64,
+    h_init: f64,
+    abstol: f64,
+    reltol: f64,
+) -> PyResult<(DVector<f64>, DMatrix<f64>)> {
+    const SAFETY: f64 = 0.9;
+    const MIN_FACTOR: f64 = 0.2;
+    const MAX_FACTOR: f64 = 10.0;
+
+    let stepper = Rk45;
+    let t_end = t_start + duration;
+    let mut current_t = t_start;
+    let mut current_h = h_init;
+    let mut current_packed = pack_augmented_state(y0, w0);
+
+    let mut rhs = |t_eval: f64, state: &DVector<f64>| augmented_rhs(dynamics, py, eps, dim, t_eval, state);
+
+    while current_t < t_end {
+        if current_t + current_h > t_end {
+            current_h = t_end - current_t;
+        }
+        if current_h <= 0.0 {
+            break;
+        }
+
+        let (next_packed, error_vec) = stepper.step(current_t, &current_packed, current_h, &mut rhs)?;
+        let error_norm = error_vec.norm();
+        let state_norm = current_packed.norm().max(next_packed.norm());
+        let tolerance = abstol + reltol * state_norm;
+        let error = if tolerance > 0.0 { error_norm / tolerance } else { 0.0 };
+
+        if error <= 1.0 {
+            current_t += current_h;
+            current_packed = next_packed;
+        }
+
+        let factor = if error > 0.0 {
+            (SAFETY * (1.0 / error).powf(0.2)).clamp(MIN_FACTOR, MAX_FACTOR)
+        } else {
+            MAX_FACTOR
+        };
+        current_h *= factor;
+    }
+
+    Ok(unpack_augmented_state(&current_packed, dim))
+}
+
+/// Computes the Kaplan-Yorke (Lyapunov) dimension from a sorted (descending) spectrum:
+/// `D_KY = k + (Σ_{i=1}^k λ_i) / |λ_{k+1}|`, where `k` is the largest number of exponents
+/// whose running sum stays non-negative.
+fn kaplan_yorke_dimension(spectrum: &DVector<f64>) -> f64 {
+    let mut sorted: Vec<f64> = spectrum.iter().cloned().collect();
+    sorted.sort_by(|a, b| b.partial_cmp(a).unwrap());
+
+    let n = sorted.len();
+    let mut running_sum = 0.0;
+    let mut k = 0;
+    for i in 0..n {
+        if running_sum + sorted[i] < 0.0 {
+            break;
+        }
+        running_sum += sorted[i];
+        k = i + 1;
+    }
+
+    if k == 0 {
+        return 0.0;
+    }
+    if k == n {
+        return n as f64;
+    }
+    k as f64 + running_sum / sorted[k].abs()
+}
+
 #[pymethods]
 impl Lyapunov {
     #[new]
@@ -53,75 +228,42 @@ impl Lyapunov {
             t_start: 0.0,
             t_end: t_transient,
             initial_h: h_init,
-            abstol,
-            reltol,
+            abstol: Tolerance::Scalar(abstol),
+            reltol: Tolerance::Scalar(reltol),
+            max_step: None,
+            min_step: None,
+            t_eval: None,
         }
         .integration_loop(py, Rk45)?;
 
         let transient_traj_obj = transient_result.bind(py).get_item(0)?;
-        // FIX: Explicitly specify the dimension as 2D for the PyArray.
         let transient_traj: &PyArray<f64, Dim<[usize; 2]>> = transient_traj_obj.extract()?;
 
         let traj_view = unsafe { transient_traj.as_array() };
         let last_row = traj_view.outer_iter().last().unwrap();
 
         let mut main_y = DVector::from_row_slice(last_row.as_slice().unwrap());
-
         let mut perturbation_w = DMatrix::<f64>::identity(state_dim, state_dim);
         let mut lyapunov_sums = DVector::<f64>::zeros(state_dim);
         let mut current_t = 0.0;
         let num_steps = (t_total / t_reorth).ceil() as usize;
         let mut spectrum_history: Vec<DVector<f64>> = Vec::with_capacity(num_steps);
 
-        println!("For loop!");
-        for s in 0..num_steps {
-            println!("Step {s}");
-            let mut initial_states: Vec<DVector<f64>> = Vec::with_capacity(state_dim + 1);
-            initial_states.push(main_y.clone());
-            for j in 0..state_dim {
-                initial_states.push(&main_y + eps * perturbation_w.column(j));
-            }
-
-            let final_states: Vec<DVector<f64>> = initial_states
-                // TODO: Fix this bug 
-                // .par_iter() 
-                .iter()
-                .map(|y0| {
-                    Python::with_gil(|py| {
-                        let y0_py = y0.as_slice().to_pyarray_bound(py);
-                        let result_tuple = Adaptive {
-                            dynamics: dynamics.clone(),
-                            initial_state: y0_py.readonly(),
-                            t_start: 0.0,
-                            t_end: t_reorth,
-                            initial_h: h_init,
-                            abstol,
-                            reltol,
-                        }
-                        .integration_loop(py, Rk45)
-                        .unwrap();
-
-                        // Get the trajectory for the current parallel task
-                        let traj_obj = result_tuple.bind(py).get_item(0).unwrap();
-                        let traj: &PyArray<f64, Dim<[usize; 2]>> = traj_obj.extract().unwrap();
-
-                        // Create a view from the correct `traj` variable
-                        let traj_view = unsafe { traj.as_array() };
-                        // Get the last state from that view
-                        let last_state = traj_view.outer_iter().last().unwrap();
-                        // Use the correct `last_state` variable to create the vector
-                        DVector::from_row_slice(last_state.as_slice().unwrap())
-                    })
-                })
-                .collect();
-
-            main_y = final_states[0].clone();
-
-            let mut evolved_w = DMatrix::<f64>::zeros(state_dim, state_dim);
-            for j in 0..state_dim {
-                let evolved_perturbation = (&final_states[j + 1] - &main_y) / eps;
-                evolved_w.set_column(j, &evolved_perturbation);
-            }
+        for _ in 0..num_steps {
+            let (evolved_y, evolved_w) = integrate_variational_window(
+                &dynamics,
+                py,
+                eps,
+                state_dim,
+                &main_y,
+                &perturbation_w,
+                current_t,
+                t_reorth,
+                h_init,
+                abstol,
+                reltol,
+            )?;
+            main_y = evolved_y;
 
             let qr = evolved_w.qr();
             let q = qr.q();
@@ -139,6 +281,7 @@ impl Lyapunov {
         }
 
         let final_spectrum = lyapunov_sums / t_total;
+        let kaplan_yorke_dim = kaplan_yorke_dimension(&final_spectrum);
         let final_spectrum_py = final_spectrum.as_slice().to_pyarray_bound(py);
 
         let history_flat: Vec<f64> = spectrum_history
@@ -150,7 +293,11 @@ impl Lyapunov {
 
         let result_tuple = PyTuple::new_bound(
             py,
-            &[final_spectrum_py.to_object(py), history_array.to_object(py)],
+            &[
+                final_spectrum_py.to_object(py),
+                history_array.to_object(py),
+                kaplan_yorke_dim.to_object(py),
+            ],
         );
         Ok(result_tuple.to_object(py))
     }
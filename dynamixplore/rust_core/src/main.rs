@@ -3,6 +3,7 @@
 // the core Rust logic without involving the Python interpreter.
 
 // Declare all the library modules.
+mod ensemble;
 mod entropy;
 mod integrators;
 mod lyapunov;
@@ -20,6 +21,11 @@ fn main() {
     let _rk45 = integrators::Rk45::new();
     let _rk4 = integrators::Rk4::new();
     let _euler = integrators::Euler::new();
+    let _trapezoidal = integrators::Trapezoidal::new();
+    let _explicit_rk = integrators::ExplicitRK::dopri5();
+    let _rosenbrock = integrators::Rosenbrock::ros2();
+    let _sdirk = integrators::Sdirk::sdirk21();
+    let _ensemble = ensemble::Ensemble::new();
     let _lyapunov = lyapunov::Lyapunov::new();
     let _entropy = entropy::Entropy::new();
     let _stats = stats::Stats::new();
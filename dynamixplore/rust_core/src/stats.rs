@@ -1,9 +1,14 @@
 // This module is dedicated to computing statistical properties of trajectories.
 
 use dashmap::DashMap;
-use numpy::PyReadonlyArray2;
+use nalgebra::{DMatrix, DVector};
+use numpy::{ndarray::IxDyn, PyArray, PyArrayMethods, PyReadonlyArray2};
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyTuple};
+use rayon::prelude::*;
+use std::collections::HashMap;
+use std::sync::Arc;
 
 use ndarray::prelude::*;
 
@@ -16,18 +21,39 @@ use ndarray::prelude::*;
 /// most frequently. This function provides a practical way to approximate this measure by
 /// creating a multi-dimensional histogram via "box counting".
 ///
-/// ## Implementation: Parallel Box Counting
+/// ## Implementation: Tree-Reduced Parallel Box Counting
 ///
-/// This calculation is "embarrassingly parallel". We use the `rayon` crate to process
-/// points concurrently and `dashmap::DashMap` for a thread-safe histogram to handle
-/// simultaneous writes from multiple threads.
+/// The one-shot `compute_*` methods below are "embarrassingly parallel": the trajectory is
+/// split into contiguous chunks, each `rayon` worker bins its own chunk into a private,
+/// unsynchronized `HashMap`, and the per-chunk maps are then merged pairwise via `reduce` —
+/// a classic tree reduction, so the only synchronization cost is proportional to the number
+/// of occupied bins, not the number of points.
+///
+/// ## Streaming Mode
+///
+/// `Stats` also doubles as a stateful, out-of-core accumulator: `accumulate` folds
+/// successive batches into a persistent, thread-safe `Arc<DashMap<...>>` histogram (shared
+/// concurrent writes are the right tradeoff here, since batches arrive one at a time rather
+/// than all at once), `finalize` emits the measure built up so far, and `reset` clears it.
+/// This lets a Python loop stream megapoints of simulation output through a single `Stats`
+/// instance without ever materializing one giant array.
 #[pyclass]
-pub struct Stats;
+pub struct Stats {
+    histogram: Arc<DashMap<Vec<i64>, usize>>,
+    #[pyo3(get)]
+    epsilon: Option<f64>,
+    #[pyo3(get)]
+    point_count: usize,
+}
 
 impl Stats {
     // Public constructor for use in main.rs test harness.
     pub fn new() -> Self {
-        Stats
+        Stats {
+            histogram: Arc::new(DashMap::new()),
+            epsilon: None,
+            point_count: 0,
+        }
     }
 }
 
@@ -38,55 +64,564 @@ impl Stats {
         Stats::new()
     }
 
-    /// Approximates the invariant measure of a system by parallel box counting.
-    #[pyo3(signature = (trajectory, epsilon))]
+    /// Approximates the invariant measure of a system by tree-reduced parallel box
+    /// counting. By default returns a sparse `{bin_coords: count}` dict. If `bounds` is
+    /// supplied as `(lower, upper)` per-dimension extents, the histogram is instead
+    /// returned as a dense numpy `ndarray` with bin edges implied by `lower`/`epsilon`,
+    /// which downstream plotting and entropy code can consume directly.
+    ///
+    /// `hasher` selects what keys the bin map internally: `"ahash"` (the default) swaps
+    /// out the default SipHash for raw throughput, which matters once state-space
+    /// dimension — and therefore bin-key length — gets large. `"blake3"` trades some of
+    /// that speed for a fully deterministic content hash, so that two `Stats` measures
+    /// computed on different machines or processes hash identical bin coordinates to
+    /// identical keys, enabling reproducible merging of measures from a distributed run.
+    #[pyo3(signature = (trajectory, epsilon, bounds=None, hasher=None))]
     fn compute_invariant_measure(
         &self,
         py: Python,
         trajectory: PyReadonlyArray2<f64>,
         epsilon: f64,
-    ) -> PyResult<Py<PyDict>> {
+        bounds: Option<(Vec<f64>, Vec<f64>)>,
+        hasher: Option<String>,
+    ) -> PyResult<PyObject> {
         if epsilon <= 0.0 {
-            return Err(pyo3::exceptions::PyValueError::new_err(
+            return Err(PyValueError::new_err(
                 "Box size 'epsilon' must be positive.",
             ));
         }
+        let hasher = BinHasher::parse(hasher.as_deref().unwrap_or("ahash"))?;
 
         // This call is unsafe because it directly accesses memory managed by Python.
         // We wrap it in an `unsafe` block to acknowledge this.
         let traj_view = trajectory.as_array();
+        let dim = traj_view.ncols();
+
+        if let Some((ref lower, ref upper)) = bounds {
+            if lower.len() != dim || upper.len() != dim {
+                return Err(PyValueError::new_err(
+                    "'bounds' must supply one (min, max) pair per state dimension.",
+                ));
+            }
+        }
+
         if traj_view.is_empty() {
-            return Ok(PyDict::new_bound(py).into());
+            return match bounds {
+                Some((lower, upper)) => dense_histogram_result(py, &HashMap::new(), &lower, &upper, epsilon),
+                None => Ok(PyDict::new_bound(py).into_py(py)),
+            };
         }
 
-        // --- 1. Create a Concurrent HashMap for Thread-Safe Counting ---
-        let histogram: DashMap<Vec<i64>, usize> = DashMap::new();
+        let points: Vec<ArrayView1<f64>> = traj_view.axis_iter(Axis(0)).collect();
+        let histogram: HashMap<Vec<i64>, usize> =
+            tree_reduced_histogram_hashed(&points, epsilon, hasher)
+                .into_iter()
+                .collect();
+
+        match bounds {
+            Some((lower, upper)) => dense_histogram_result(py, &histogram, &lower, &upper, epsilon),
+            None => {
+                // --- Convert the sparse histogram into a Python dictionary ---
+                let result_dict = PyDict::new_bound(py);
+                for (bin_coords, count) in histogram {
+                    // Explicitly convert the Rust Vec into a Python tuple, which is hashable.
+                    let key_tuple = PyTuple::new_bound(py, &bin_coords);
+                    result_dict.set_item(key_tuple, count)?;
+                }
+                Ok(result_dict.into_py(py))
+            }
+        }
+    }
 
-        // --- 2. Iterate Over Trajectory in Parallel ---
-        // FIX: Replaced `.axis_iter(Axis(0)).into_par_iter()` with the correct method
-        // from `ndarray-rayon` for parallel iteration over an axis.
-        traj_view.axis_iter(Axis(0)).for_each(|point_view| {
-            // --- 3. Determine the Bin Coordinates for Each Point ---
+    /// # Generalized (Rényi) Dimension Spectrum
+    ///
+    /// ## Mathematical and Scientific Motivation
+    ///
+    /// Runs box counting at a geometric sequence of scales `epsilons` and, for each
+    /// requested order `q`, extracts the generalized fractal dimension `D_q` as the slope
+    /// of the Rényi partition-sum scaling law. With bin occupation probabilities
+    /// `p_i = count_i / N_total`:
+    ///
+    /// - For `q != 1`: `I_q(ε) = 1/(q-1) · log(Σ p_i^q)`
+    /// - For `q = 1` (Shannon/information-dimension limit): `I_q(ε) = Σ p_i·log(p_i)`
+    ///
+    /// Both forms scale as `I_q(ε) ≈ D_q·log(ε) + const`, so `D_q` is recovered directly as
+    /// the OLS slope of `I_q(ε)` against `log(ε)` — no extra sign flip is needed, since it
+    /// already agrees with the conventional `D_0` = capacity dimension, `D_1` = information
+    /// dimension, `D_2` = correlation dimension. The regression R² is returned alongside
+    /// each `D_q` so callers can judge how linear (how well-scaled) the fit really is.
+    #[pyo3(signature = (trajectory, epsilons, q_values))]
+    fn compute_dimension_spectrum(
+        &self,
+        py: Python,
+        trajectory: PyReadonlyArray2<f64>,
+        epsilons: Vec<f64>,
+        q_values: Vec<f64>,
+    ) -> PyResult<Py<PyDict>> {
+        if epsilons.len() < 2 {
+            return Err(PyValueError::new_err(
+                "'epsilons' must contain at least two scales to fit a slope.",
+            ));
+        }
+        if epsilons.iter().any(|&eps| eps <= 0.0) {
+            return Err(PyValueError::new_err("All 'epsilons' must be positive."));
+        }
+
+        let traj_view = trajectory.as_array();
+        if traj_view.is_empty() {
+            return Err(PyValueError::new_err(
+                "Trajectory must contain at least one point.",
+            ));
+        }
+        let n_total = traj_view.nrows() as f64;
+        let points: Vec<ArrayView1<f64>> = traj_view.axis_iter(Axis(0)).collect();
+
+        let log_eps: Vec<f64> = epsilons.iter().map(|&eps| eps.ln()).collect();
+        let probabilities_per_scale: Vec<Vec<f64>> = epsilons
+            .iter()
+            .map(|&eps| {
+                tree_reduced_histogram(&points, eps)
+                    .values()
+                    .map(|&count| count as f64 / n_total)
+                    .collect()
+            })
+            .collect();
+
+        let result_dict = PyDict::new_bound(py);
+        for &q in &q_values {
+            let i_q: Vec<f64> = probabilities_per_scale
+                .iter()
+                .map(|probabilities| renyi_partition_sum(probabilities, q))
+                .collect();
+            let (d_q, r_squared) = ordinary_least_squares(&log_eps, &i_q);
+            result_dict.set_item(q, (d_q, r_squared))?;
+        }
+        Ok(result_dict.into())
+    }
+
+    /// # Periodic-Orbit / Recurrence Detection
+    ///
+    /// ## Mathematical and Scientific Motivation
+    ///
+    /// The standard "remember the state, detect the repeat" technique for finding the
+    /// eventual period of a deterministic discrete map: discretize each trajectory point
+    /// to its box coordinate (reusing the same binning as box counting), producing a
+    /// symbol sequence, and record the first occurrence index of every distinct symbol.
+    /// The first time a symbol recurs at index `j` having first occurred at `i`, `j - i`
+    /// is a *candidate* period. Because a single revisited symbol could be coincidental
+    /// rather than a genuine limit cycle, the candidate is only confirmed once the whole
+    /// symbol block `[i, j)` is found to repeat forward, block-for-block, to the end of
+    /// the trajectory (discretization to box coordinates already encodes the tolerance, so
+    /// "repeats within tolerance" reduces to exact symbol-block equality). Gives users a
+    /// cheap way to distinguish transients from limit cycles before running the much more
+    /// expensive Lyapunov spectrum analysis.
+    #[pyo3(signature = (trajectory, epsilon))]
+    fn detect_cycle(
+        &self,
+        _py: Python,
+        trajectory: PyReadonlyArray2<f64>,
+        epsilon: f64,
+    ) -> PyResult<Option<(usize, usize, usize)>> {
+        if epsilon <= 0.0 {
+            return Err(PyValueError::new_err(
+                "Box size 'epsilon' must be positive.",
+            ));
+        }
+
+        let traj_view = trajectory.as_array();
+        if traj_view.is_empty() {
+            return Ok(None);
+        }
+
+        let symbols: Vec<Vec<i64>> = traj_view
+            .axis_iter(Axis(0))
+            .map(|point| point.iter().map(|&coord| (coord / epsilon).floor() as i64).collect())
+            .collect();
+
+        let mut first_seen: HashMap<Vec<i64>, usize> = HashMap::new();
+        for (j, symbol) in symbols.iter().enumerate() {
+            match first_seen.get(symbol) {
+                Some(&i) => {
+                    let period = j - i;
+                    let num_repeats = count_confirmed_repeats(&symbols, i, period);
+                    // The repeat scan stops either at a mismatching block or because no full
+                    // block is left; only the latter means the cycle held to the end.
+                    let reached_end = i + (num_repeats + 2) * period > symbols.len();
+                    if num_repeats >= 1 && reached_end {
+                        return Ok(Some((i, period, num_repeats)));
+                    }
+                    // Coincidental revisit, not a genuine cycle — keep scanning.
+                }
+                None => {
+                    first_seen.insert(symbol.clone(), j);
+                }
+            }
+        }
+        Ok(None)
+    }
+
+    /// # Streaming Accumulation (Out-of-Core Invariant Measure)
+    ///
+    /// ## Mathematical and Scientific Motivation
+    ///
+    /// Folds one more batch of trajectory points into this `Stats` instance's persistent
+    /// histogram, using the same box-counting binning as [`Stats::compute_invariant_measure`]
+    /// but writing concurrently into a shared `DashMap` rather than building one throwaway
+    /// map per call — the natural tradeoff once points arrive incrementally instead of all
+    /// at once. The scale `epsilon` is fixed by the first call and must match on every
+    /// subsequent call until [`Stats::reset`] is used; call `finalize` at any point to read
+    /// out the measure accumulated so far.
+    #[pyo3(signature = (batch, epsilon))]
+    fn accumulate(&mut self, batch: PyReadonlyArray2<f64>, epsilon: f64) -> PyResult<()> {
+        if epsilon <= 0.0 {
+            return Err(PyValueError::new_err(
+                "Box size 'epsilon' must be positive.",
+            ));
+        }
+        match self.epsilon {
+            Some(existing) if (existing - epsilon).abs() > 1e-12 => {
+                return Err(PyValueError::new_err(format!(
+                    "This Stats accumulator was initialized with epsilon={existing}; \
+                     got epsilon={epsilon}. Call reset() to change the scale.",
+                )));
+            }
+            _ => self.epsilon = Some(epsilon),
+        }
+
+        let batch_view = batch.as_array();
+        let points: Vec<ArrayView1<f64>> = batch_view.axis_iter(Axis(0)).collect();
+        let histogram = Arc::clone(&self.histogram);
+        points.par_iter().for_each(|point_view| {
             let bin_coords: Vec<i64> = point_view
                 .iter()
                 .map(|&coord| (coord / epsilon).floor() as i64)
                 .collect();
-
-            // --- 4. Increment the Count for the Corresponding Bin ---
             *histogram.entry(bin_coords).or_insert(0) += 1;
         });
 
-        // --- 5. Convert the Rust DashMap to a Python Dictionary ---
+        self.point_count += points.len();
+        Ok(())
+    }
+
+    /// Emits the histogram accumulated so far via [`Stats::accumulate`] as a sparse
+    /// `{bin_coords: count}` dict, in the same format as [`Stats::compute_invariant_measure`].
+    fn finalize(&self, py: Python) -> PyResult<Py<PyDict>> {
         let result_dict = PyDict::new_bound(py);
-        for item in histogram.into_iter() {
-            let key_vec = item.0;   // This is the Vec<i64>
-            let value = item.1; // This is the usize count
+        for entry in self.histogram.iter() {
+            let key_tuple = PyTuple::new_bound(py, entry.key());
+            result_dict.set_item(key_tuple, *entry.value())?;
+        }
+        Ok(result_dict.into())
+    }
 
-            // FIX: Explicitly convert the Rust Vec into a Python tuple, which is hashable.
-            let key_tuple = PyTuple::new_bound(py, &key_vec);
-            result_dict.set_item(key_tuple, value)?;
+    /// Clears the accumulated histogram, point count, and fixed `epsilon`, so this `Stats`
+    /// instance can be reused to accumulate a fresh measure (possibly at a different scale).
+    fn reset(&mut self) {
+        self.histogram = Arc::new(DashMap::new());
+        self.epsilon = None;
+        self.point_count = 0;
+    }
+
+    /// # 2-Wasserstein Distance Between Invariant Measures
+    ///
+    /// ## Mathematical and Scientific Motivation
+    ///
+    /// Compares two attractors (e.g. from different integrators, or either side of a
+    /// bifurcation) by approximating each trajectory's invariant measure as a Gaussian
+    /// fit to its mean `μ` and covariance `Σ`, then computing the squared 2-Wasserstein
+    /// distance in closed form:
+    ///
+    /// `W₂² = ‖μ₁ − μ₂‖² + Tr(Σ₁ + Σ₂ − 2·(Σ₁^{1/2}·Σ₂·Σ₁^{1/2})^{1/2})`
+    ///
+    /// This is exact when both measures are Gaussian, and otherwise a principled
+    /// second-moment distance between them.
+    #[pyo3(signature = (trajectory_a, trajectory_b))]
+    fn compare_measures(
+        &self,
+        _py: Python,
+        trajectory_a: PyReadonlyArray2<f64>,
+        trajectory_b: PyReadonlyArray2<f64>,
+    ) -> PyResult<f64> {
+        let a = trajectory_a.as_array();
+        let b = trajectory_b.as_array();
+
+        if a.is_empty() || b.is_empty() {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "Both trajectories must contain at least one point.",
+            ));
+        }
+        if a.ncols() != b.ncols() {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "Both trajectories must have the same state dimension.",
+            ));
         }
 
-        Ok(result_dict.into())
+        let (mean_a, cov_a) = mean_and_covariance(&a);
+        let (mean_b, cov_b) = mean_and_covariance(&b);
+
+        let mean_diff_sq = (&mean_a - &mean_b).norm_squared();
+
+        let sqrt_cov_a = symmetric_psd_sqrt(&cov_a);
+        let inner = &sqrt_cov_a * &cov_b * &sqrt_cov_a;
+        let sqrt_inner = symmetric_psd_sqrt(&inner);
+
+        let trace_term = (cov_a.trace() + cov_b.trace()) - 2.0 * sqrt_inner.trace();
+
+        Ok(mean_diff_sq + trace_term)
+    }
+}
+
+/// Which fast hash function backs the box-counting path's bin-key map, selected by
+/// `compute_invariant_measure`'s `hasher` argument.
+#[derive(Clone, Copy)]
+enum BinHasher {
+    /// Raw throughput; the seed is freshly randomized every call to
+    /// `compute_invariant_measure`, so digests are only stable within that one call.
+    Ahash,
+    /// A slower but fully deterministic content hash, so identical bin coordinates hash
+    /// to identical keys across machines and processes.
+    Blake3,
+}
+
+impl BinHasher {
+    fn parse(name: &str) -> PyResult<Self> {
+        match name {
+            "ahash" => Ok(BinHasher::Ahash),
+            "blake3" => Ok(BinHasher::Blake3),
+            other => Err(PyValueError::new_err(format!(
+                "Unknown hasher '{other}'; expected 'ahash' or 'blake3'.",
+            ))),
+        }
+    }
+}
+
+/// Computes the fixed-width digest of a bin-coordinate vector used to key the hashed
+/// box-counting path, over the coordinates' little-endian byte representation.
+///
+/// `ahash_state` must be the *same* `RandomState` for every call within one histogram
+/// build — `RandomState` derives a fresh random key on every `::new()`, so constructing
+/// one per point (rather than once per call) would make identical bin coordinates hash to
+/// different digests almost every time, defeating deduplication entirely.
+fn hash_bin_coords(bin_coords: &[i64], hasher: BinHasher, ahash_state: &ahash::RandomState) -> Vec<u8> {
+    let bytes: Vec<u8> = bin_coords.iter().flat_map(|&c| c.to_le_bytes()).collect();
+    match hasher {
+        BinHasher::Ahash => {
+            use std::hash::{BuildHasher, Hash, Hasher};
+            let mut state = ahash_state.build_hasher();
+            bytes.hash(&mut state);
+            state.finish().to_le_bytes().to_vec()
+        }
+        BinHasher::Blake3 => blake3::hash(&bytes).as_bytes().to_vec(),
+    }
+}
+
+/// Tree-reduced box counting identical in structure to [`tree_reduced_histogram`], but
+/// keying the per-chunk local maps (and the final merge) on a fixed-width hash of the bin
+/// coordinates rather than the coordinate `Vec<i64>` itself, since in high dimensions the
+/// cost of hashing long `Vec<i64>` keys with the default SipHash dominates the hot loop.
+/// The original coordinates are kept alongside each count — one copy per occupied bin —
+/// so the caller can still report results by bin coordinate.
+fn tree_reduced_histogram_hashed(
+    points: &[ArrayView1<f64>],
+    epsilon: f64,
+    hasher: BinHasher,
+) -> Vec<(Vec<i64>, usize)> {
+    let num_chunks = rayon::current_num_threads().max(1);
+    let chunk_size = (points.len() / num_chunks).max(1);
+    // Built once per call and shared (by reference) across every chunk and point, so all
+    // bin coordinates in this histogram are hashed under the same key.
+    let ahash_state = ahash::RandomState::new();
+
+    let merged: HashMap<Vec<u8>, (Vec<i64>, usize)> = points
+        .par_chunks(chunk_size)
+        .map(|chunk| {
+            let mut local: HashMap<Vec<u8>, (Vec<i64>, usize)> = HashMap::new();
+            for point_view in chunk {
+                let bin_coords: Vec<i64> = point_view
+                    .iter()
+                    .map(|&coord| (coord / epsilon).floor() as i64)
+                    .collect();
+                let digest = hash_bin_coords(&bin_coords, hasher, &ahash_state);
+                let entry = local.entry(digest).or_insert_with(|| (bin_coords, 0));
+                entry.1 += 1;
+            }
+            local
+        })
+        .reduce(HashMap::new, |mut a, b| {
+            for (digest, (coords, count)) in b {
+                let entry = a.entry(digest).or_insert_with(|| (coords, 0));
+                entry.1 += count;
+            }
+            a
+        });
+
+    merged.into_values().collect()
+}
+
+/// Bins `points` into occupancy counts at box size `epsilon`, via the tree-reduced scheme
+/// shared by [`Stats::compute_invariant_measure`] and [`Stats::compute_dimension_spectrum`]:
+/// each `rayon` chunk fills its own unsynchronized local `HashMap`, and the per-chunk maps
+/// are merged pairwise with `reduce`.
+fn tree_reduced_histogram(points: &[ArrayView1<f64>], epsilon: f64) -> HashMap<Vec<i64>, usize> {
+    let num_chunks = rayon::current_num_threads().max(1);
+    let chunk_size = (points.len() / num_chunks).max(1);
+
+    points
+        .par_chunks(chunk_size)
+        .map(|chunk| {
+            let mut local: HashMap<Vec<i64>, usize> = HashMap::new();
+            for point_view in chunk {
+                let bin_coords: Vec<i64> = point_view
+                    .iter()
+                    .map(|&coord| (coord / epsilon).floor() as i64)
+                    .collect();
+                *local.entry(bin_coords).or_insert(0) += 1;
+            }
+            local
+        })
+        .reduce(HashMap::new, |mut a, b| {
+            for (bin_coords, count) in b {
+                *a.entry(bin_coords).or_insert(0) += count;
+            }
+            a
+        })
+}
+
+/// Evaluates the Rényi partition-sum functional `I_q` at a single scale, given the bin
+/// occupation probabilities at that scale: the Shannon form at `q = 1`, and the general
+/// `1/(q-1)·log(Σ p_i^q)` form otherwise.
+fn renyi_partition_sum(probabilities: &[f64], q: f64) -> f64 {
+    if (q - 1.0).abs() < 1e-9 {
+        probabilities
+            .iter()
+            .map(|&p| if p > 0.0 { p * p.ln() } else { 0.0 })
+            .sum()
+    } else {
+        let partition_sum: f64 = probabilities.iter().map(|&p| p.powf(q)).sum();
+        (1.0 / (q - 1.0)) * partition_sum.ln()
+    }
+}
+
+/// Counts how many full `period`-length symbol blocks starting right after `[onset,
+/// onset + period)` match that initial block exactly, stopping at the first mismatch or
+/// once the trajectory runs out — i.e. how many times the candidate cycle is confirmed to
+/// genuinely repeat forward.
+fn count_confirmed_repeats(symbols: &[Vec<i64>], onset: usize, period: usize) -> usize {
+    let n = symbols.len();
+    let mut repeats = 0;
+    let mut k = 1;
+    loop {
+        let start = onset + k * period;
+        if start + period > n {
+            break;
+        }
+        let blocks_match = (0..period).all(|idx| symbols[onset + idx] == symbols[start + idx]);
+        if !blocks_match {
+            break;
+        }
+        repeats += 1;
+        k += 1;
+    }
+    repeats
+}
+
+/// Ordinary least-squares fit of `y = slope·x + intercept`, returning `(slope, r_squared)`.
+fn ordinary_least_squares(x: &[f64], y: &[f64]) -> (f64, f64) {
+    let n = x.len() as f64;
+    let mean_x = x.iter().sum::<f64>() / n;
+    let mean_y = y.iter().sum::<f64>() / n;
+
+    let mut cov_xy = 0.0;
+    let mut var_x = 0.0;
+    for (&xi, &yi) in x.iter().zip(y.iter()) {
+        cov_xy += (xi - mean_x) * (yi - mean_y);
+        var_x += (xi - mean_x).powi(2);
+    }
+    let slope = if var_x > 0.0 { cov_xy / var_x } else { 0.0 };
+    let intercept = mean_y - slope * mean_x;
+
+    let mut ss_res = 0.0;
+    let mut ss_tot = 0.0;
+    for (&xi, &yi) in x.iter().zip(y.iter()) {
+        let predicted = slope * xi + intercept;
+        ss_res += (yi - predicted).powi(2);
+        ss_tot += (yi - mean_y).powi(2);
+    }
+    let r_squared = if ss_tot > 0.0 { 1.0 - ss_res / ss_tot } else { 1.0 };
+
+    (slope, r_squared)
+}
+
+/// Folds a sparse `{bin_coords: count}` histogram into a dense row-major `ndarray` sized to
+/// the epsilon-aligned bins (edges at multiples of `epsilon`) that cover
+/// `[lower[d], upper[d])` along each dimension `d`.
+/// Bins falling outside those explicit bounds are dropped, since the caller asked for a
+/// fixed-shape array rather than the unbounded sparse map.
+fn dense_histogram_result(
+    py: Python,
+    histogram: &HashMap<Vec<i64>, usize>,
+    lower: &[f64],
+    upper: &[f64],
+    epsilon: f64,
+) -> PyResult<PyObject> {
+    let dim = lower.len();
+    let lower_bin: Vec<i64> = lower.iter().map(|&l| (l / epsilon).floor() as i64).collect();
+    let shape: Vec<usize> = (0..dim)
+        .map(|d| ((upper[d] / epsilon).ceil() as i64 - lower_bin[d]).max(0) as usize)
+        .collect();
+    let total: usize = shape.iter().product();
+
+    let mut dense = vec![0f64; total];
+    for (bin_coords, &count) in histogram.iter() {
+        let mut idx = 0usize;
+        let mut in_bounds = true;
+        for d in 0..dim {
+            let rel = bin_coords[d] - lower_bin[d];
+            if rel < 0 || rel as usize >= shape[d] {
+                in_bounds = false;
+                break;
+            }
+            idx = idx * shape[d] + rel as usize;
+        }
+        if in_bounds {
+            dense[idx] += count as f64;
+        }
+    }
+
+    let array = PyArray::from_vec_bound(py, dense).reshape(IxDyn(&shape))?;
+    Ok(array.to_object(py))
+}
+
+/// Computes the sample mean vector and (biased) covariance matrix of a set of points,
+/// one point per row.
+fn mean_and_covariance(points: &ArrayView2<f64>) -> (DVector<f64>, DMatrix<f64>) {
+    let n = points.nrows() as f64;
+    let dim = points.ncols();
+
+    let mut mean = DVector::<f64>::zeros(dim);
+    for row in points.outer_iter() {
+        for (i, &v) in row.iter().enumerate() {
+            mean[i] += v;
+        }
+    }
+    mean /= n;
+
+    let mut covariance = DMatrix::<f64>::zeros(dim, dim);
+    for row in points.outer_iter() {
+        let centered = DVector::from_iterator(dim, row.iter().enumerate().map(|(i, &v)| v - mean[i]));
+        covariance += &centered * centered.transpose();
     }
+    covariance /= n;
+
+    (mean, covariance)
+}
+
+/// Computes the principal square root of a symmetric positive-semidefinite matrix via
+/// eigendecomposition: `Q·diag(√λ)·Qᵀ`, clamping tiny negative eigenvalues (numerical
+/// noise) to zero before taking the square root.
+fn symmetric_psd_sqrt(matrix: &DMatrix<f64>) -> DMatrix<f64> {
+    let eigen = matrix.clone().symmetric_eigen();
+    let sqrt_eigenvalues = eigen.eigenvalues.map(|lambda| lambda.max(0.0).sqrt());
+    let q = &eigen.eigenvectors;
+    q * DMatrix::from_diagonal(&sqrt_eigenvalues) * q.transpose()
 }
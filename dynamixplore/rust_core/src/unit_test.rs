@@ -1,7 +1,11 @@
 use crate::*;
 
+use integrators::Approach;
 use nalgebra::DVector;
 use ndarray::Array2;
+use numpy::{ndarray::Dim, PyArray, PyArrayMethods, PyReadonlyArray1, ToPyArray};
+use pyo3::prelude::*;
+use pyo3::types::PyModule;
 
 // --- 1. Define the Dynamical System in Rust ---
 // This is the Rust equivalent of the `lorenz_system` function in demo.py.
@@ -108,6 +112,95 @@ fn test_apples() {
     println!("\n--- Test Harness Finished ---");
 }
 
+// --- Forward/Backward Round-Trip Test ---
+// Drives `Explicit::integration_loop` itself (not just the underlying `Stepper`), with a
+// genuine `t_end < t_start` call, so the `direction`/backward-integration logic of
+// `integrators.rs`'s `Explicit::integration_loop` has real coverage. The `Adaptive` and
+// `Implicit` loops are not exercised here.
+// A trivial Python-defined callback, built via `PyModule::from_code_bound` under a GIL
+// acquired with `Python::with_gil`, stands in for the user-supplied `dynamics` callable.
+#[test]
+fn test_integration_loop_backward_direction_recovers_initial_state() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        // Simple linear decay dy/dt = -y; only the forward/backward round trip is checked.
+        let dynamics = PyModule::from_code_bound(py, "def decay(t, y):\n    return -y\n", "decay.py", "decay")
+            .unwrap()
+            .getattr("decay")
+            .unwrap()
+            .into_py(py);
+
+        let initial_state = vec![1.0, 2.0, -3.0];
+        let t_start = 0.0;
+        let t_end = 2.0;
+        let h = 0.01;
+
+        let to_readonly = |values: &[f64]| -> PyReadonlyArray1<f64> {
+            let array_obj = values.to_pyarray_bound(py).into_py(py);
+            array_obj.extract::<PyReadonlyArray1<f64>>(py).unwrap()
+        };
+
+        let forward_result = integrators::Explicit {
+            dynamics: dynamics.clone(),
+            initial_state: to_readonly(&initial_state),
+            t_start,
+            t_end,
+            h,
+            t_eval: None,
+        }
+        .integration_loop(py, integrators::Rk4)
+        .unwrap();
+
+        let forward_traj_obj = forward_result.bind(py).get_item(0).unwrap();
+        let forward_traj: &PyArray<f64, Dim<[usize; 2]>> = forward_traj_obj.extract().unwrap();
+        let forward_view = unsafe { forward_traj.as_array() };
+        let forward_final: Vec<f64> = forward_view.outer_iter().last().unwrap().to_vec();
+
+        // Integrate backward from t_end back to t_start: `t_end < t_start` here is exactly
+        // the condition `integration_loop` detects and flips `h`'s sign for.
+        let backward_result = integrators::Explicit {
+            dynamics,
+            initial_state: to_readonly(&forward_final),
+            t_start: t_end,
+            t_end: t_start,
+            h,
+            t_eval: None,
+        }
+        .integration_loop(py, integrators::Rk4)
+        .unwrap();
+
+        let backward_times_obj = backward_result.bind(py).get_item(1).unwrap();
+        let backward_times: &PyArray<f64, Dim<[usize; 1]>> = backward_times_obj.extract().unwrap();
+        let times_view = unsafe { backward_times.as_array() };
+        assert!(
+            times_view[0] > times_view[times_view.len() - 1],
+            "backward integration's accompanying time vector should run in decreasing order"
+        );
+
+        let backward_traj_obj = backward_result.bind(py).get_item(0).unwrap();
+        let backward_traj: &PyArray<f64, Dim<[usize; 2]>> = backward_traj_obj.extract().unwrap();
+        let backward_view = unsafe { backward_traj.as_array() };
+        let recovered = backward_view.outer_iter().last().unwrap();
+
+        let recovered_error: f64 = recovered
+            .iter()
+            .zip(initial_state.iter())
+            .map(|(a, b)| (a - b).powi(2))
+            .sum::<f64>()
+            .sqrt();
+        println!(
+            "    ✓ Forward/backward round trip via integration_loop: recovered={:?}, error={}",
+            recovered.to_vec(),
+            recovered_error
+        );
+        assert!(
+            recovered_error < 1e-4,
+            "backward integration did not recover the initial state (error = {})",
+            recovered_error
+        );
+    });
+}
+
 impl stats::Stats {
     fn compute_invariant_measure_rust(
         &self,